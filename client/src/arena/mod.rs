@@ -0,0 +1,55 @@
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::network::{Arena, ArenaPlayer, NetworkConnection, OnInsert, OnUpdate, Stdb};
+
+pub use systems::*;
+
+const ARENA_MINUTES: u8 = 30;
+
+#[derive(Component)]
+pub struct ArenaScreen;
+
+#[derive(Component)]
+pub struct ArenaPanel;
+
+#[derive(Component)]
+pub struct StandingsPanel;
+
+/// Time left in the arena, counted down locally between updates
+#[derive(Component)]
+pub struct ArenaCountdown {
+    pub ends_at_micros: i64,
+}
+
+#[derive(Component, Clone, Copy)]
+pub enum ArenaButton {
+    Back,
+    Create,
+    Join(u32),
+    Leave(u32),
+}
+
+pub fn setup_systems(app: &mut App) {
+    app.add_systems(
+        OnEnter(AppState::ArenaScreen),
+        (arena_screen, refresh_arena_screen).chain(),
+    );
+    app.add_systems(
+        Update,
+        (
+            arena_button_interaction,
+            arena_countdown_update,
+            refresh_arena_screen.run_if(
+                on_event::<Stdb<OnInsert<Arena>>>
+                    .or(on_event::<Stdb<OnUpdate<Arena>>>)
+                    .or(on_event::<Stdb<OnInsert<ArenaPlayer>>>)
+                    .or(on_event::<Stdb<OnUpdate<ArenaPlayer>>>),
+            ),
+        )
+            .run_if(in_state(AppState::ArenaScreen))
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+}
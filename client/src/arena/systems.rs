@@ -0,0 +1,283 @@
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, Table, Timestamp};
+
+use crate::AppState;
+use crate::network::{
+    Arena, ArenaPlayer, ArenaPlayerTableAccess, ArenaTableAccess, CreateArena, JoinArena,
+    LeaveArena, NetworkAuth, NetworkConnection,
+};
+use crate::resources::FontSpaceGrotesk;
+use crate::ui::{UiButtonStyle, colors, player_label, spawn_small_button};
+
+use super::{ARENA_MINUTES, ArenaButton, ArenaCountdown, ArenaPanel, ArenaScreen, StandingsPanel};
+
+pub fn arena_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
+    cmds.spawn((
+        StateScoped(AppState::ArenaScreen),
+        ArenaScreen,
+        Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.),
+            padding: UiRect::top(Val::Px(6.)),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.975)),
+        ZIndex(2),
+    ))
+    .with_children(|parent| {
+        parent
+            .spawn(Node {
+                width: Val::Percent(96.),
+                justify_content: JustifyContent::SpaceBetween,
+                ..default()
+            })
+            .with_children(|top_bar| {
+                spawn_small_button(top_bar, &font, ArenaButton::Back, "Back");
+                spawn_small_button(top_bar, &font, ArenaButton::Create, "+Arena");
+            });
+
+        parent.spawn((
+            ArenaPanel,
+            Node {
+                width: Val::Percent(96.),
+                height: Val::Percent(30.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+        ));
+
+        parent.spawn((
+            StandingsPanel,
+            Node {
+                width: Val::Percent(96.),
+                height: Val::Percent(55.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(1.),
+                overflow: Overflow::scroll_y(),
+                border: UiRect::top(Val::Px(1.)),
+                ..default()
+            },
+            BorderColor(colors::DODGER_BLUE.with_alpha(0.5).into()),
+        ));
+    });
+}
+
+/// Rebuilds the open arenas and the live standings of the player's arena from the cache
+pub fn refresh_arena_screen(
+    mut cmds: Commands,
+    arena_panel_q: Query<Entity, With<ArenaPanel>>,
+    standings_panel_q: Query<Entity, With<StandingsPanel>>,
+    connection: Res<NetworkConnection>,
+    network_auth: Res<NetworkAuth>,
+    font: Res<FontSpaceGrotesk>,
+) -> Result {
+    let arena_panel = arena_panel_q.single()?;
+    let standings_panel = standings_panel_q.single()?;
+    let me = network_auth.identity;
+
+    let mut arenas: Vec<Arena> = connection.db().arena().iter().collect();
+    arenas.sort_by_key(|a| std::cmp::Reverse(a.id));
+    let players_of = |arena_id: u32| {
+        connection
+            .db()
+            .arena_player()
+            .iter()
+            .filter(|p| p.arena_id == arena_id)
+            .collect::<Vec<ArenaPlayer>>()
+    };
+
+    cmds.entity(arena_panel)
+        .despawn_related::<Children>()
+        .with_children(|list| {
+            for arena in arenas.iter().filter(|a| !a.finished) {
+                let players = players_of(arena.id);
+                let active = players.iter().any(|p| p.player == me && p.active);
+                list.spawn(Node {
+                    width: Val::Percent(100.),
+                    min_height: Val::Px(22.),
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Label,
+                        Text::new(format!(
+                            "#{} {} players",
+                            arena.id,
+                            players.iter().filter(|p| p.active).count()
+                        )),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(colors::GOLD.into()),
+                    ));
+                    if active {
+                        spawn_small_button(row, &font, ArenaButton::Leave(arena.id), "Leave");
+                    } else {
+                        spawn_small_button(row, &font, ArenaButton::Join(arena.id), "Join");
+                    }
+                });
+            }
+        });
+
+    // Standings of the latest arena the player took part in
+    cmds.entity(standings_panel).despawn_related::<Children>();
+    let Some(arena) = arenas
+        .iter()
+        .find(|a| players_of(a.id).iter().any(|p| p.player == me))
+    else {
+        return Ok(());
+    };
+
+    let mut standings = players_of(arena.id);
+    standings.sort_by_key(|p| (std::cmp::Reverse(p.points), p.id));
+
+    cmds.entity(standings_panel).with_children(|panel| {
+        let mut header = panel.spawn((
+            Label,
+            Text::new(format!("Arena #{} ", arena.id)),
+            TextFont {
+                font: font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(colors::GREEN_YELLOW.into()),
+        ));
+        if arena.finished {
+            header.with_child((
+                TextSpan::new("Final"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(colors::DEEP_PINK.into()),
+            ));
+        } else {
+            header.with_child((
+                ArenaCountdown {
+                    ends_at_micros: arena.ends_at.to_micros_since_unix_epoch(),
+                },
+                TextSpan::default(),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(colors::GOLD.into()),
+            ));
+        }
+
+        for (rank, arena_player) in standings.iter().enumerate() {
+            let name_color = if arena_player.player == me {
+                colors::GREEN_YELLOW
+            } else {
+                colors::GOLD
+            };
+            // Players who left keep their place but are dimmed
+            let alpha = if arena_player.active || arena.finished {
+                1.
+            } else {
+                0.5
+            };
+            // Two wins in a row double the points of the next one
+            let streak = if arena_player.streak >= 2 { " x2" } else { "" };
+
+            panel
+                .spawn(Node {
+                    width: Val::Percent(100.),
+                    justify_content: JustifyContent::SpaceBetween,
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Label,
+                        Text::new(format!(
+                            "{}. {}",
+                            rank + 1,
+                            player_label(&arena_player.player, &me)
+                        )),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(name_color.with_alpha(alpha).into()),
+                    ));
+                    row.spawn((
+                        Label,
+                        Text::new(format!("{}{streak}", arena_player.points)),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(colors::DODGER_BLUE.with_alpha(alpha).into()),
+                    ));
+                });
+        }
+    });
+    Ok(())
+}
+
+pub fn arena_countdown_update(mut countdown_q: Query<(&mut TextSpan, &ArenaCountdown)>) {
+    let now = Timestamp::now().to_micros_since_unix_epoch();
+    for (mut countdown_label, countdown) in &mut countdown_q {
+        let secs_left = (countdown.ends_at_micros - now).max(0) / 1_000_000;
+        **countdown_label = format!("{:02}:{:02}", secs_left / 60, secs_left % 60);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn arena_button_interaction(
+    mut interaction_query: Query<
+        (
+            &ArenaButton,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut TextColor>,
+    mut game_state: ResMut<NextState<AppState>>,
+    conn: Res<NetworkConnection>,
+) {
+    for (arena_button, button_style, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = button_style.text_color.into();
+                match *arena_button {
+                    ArenaButton::Back => game_state.set(AppState::TournamentScreen),
+                    ArenaButton::Create => conn.reducers.create_arena(ARENA_MINUTES).unwrap(),
+                    ArenaButton::Join(id) => conn.reducers.join_arena(id).unwrap(),
+                    ArenaButton::Leave(id) => conn.reducers.leave_arena(id).unwrap(),
+                }
+            }
+            Interaction::Hovered => {
+                *color = button_style.text_color.into();
+                *text_color = button_style.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = button_style.color.into();
+                *text_color = button_style.text_color.into();
+                *border_color = button_style.border_color.into();
+            }
+        }
+    }
+}
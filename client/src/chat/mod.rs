@@ -0,0 +1,102 @@
+mod reactions;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::network::{Block, ChatMessage, Emote, GameReaction, NetworkConnection, OnInsert, Stdb};
+use crate::resources::BoardData;
+
+pub use reactions::*;
+pub use systems::*;
+
+/// Channel of the messages that aren't tied to a game
+pub const LOBBY_CHANNEL: &str = "lobby";
+// Mirrors the server, longer drafts would be rejected anyway
+const MAX_MESSAGE_LENGTH: usize = 140;
+// Messages kept on screen
+const CHAT_HISTORY: usize = 20;
+// The open panel widens the window by its own width
+const CHAT_PANEL_WIDTH: f32 = 180.;
+
+/// Chat of the current screen, the panel starts collapsed
+#[derive(Resource)]
+pub struct ChatState {
+    pub channel: String,
+    pub open: bool,
+    pub draft: String,
+}
+
+impl ChatState {
+    pub fn new(channel: impl Into<String>) -> Self {
+        Self {
+            channel: channel.into(),
+            open: false,
+            draft: String::new(),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct ChatToggleButton;
+
+#[derive(Component)]
+pub struct ChatPanel;
+
+#[derive(Component)]
+pub struct ChatLog;
+
+#[derive(Component)]
+pub struct ChatInputLine;
+
+/// Hides the other side's reactions, kept from one game to the next
+#[derive(Resource, Default)]
+pub struct ReactionsMuted(pub bool);
+
+#[derive(Component)]
+pub struct ReactionButton(pub Emote);
+
+#[derive(Component)]
+pub struct MuteReactionsButton;
+
+#[derive(Component)]
+pub struct ReactionBubble(pub Timer);
+
+pub fn setup_systems(app: &mut App) {
+    app.init_resource::<ReactionsMuted>();
+
+    app.add_systems(
+        OnEnter(AppState::GameInProgress),
+        game_chat.run_if(resource_exists::<NetworkConnection>),
+    );
+    app.add_systems(
+        OnEnter(AppState::LobbyScreen),
+        lobby_chat.run_if(resource_exists::<NetworkConnection>),
+    );
+    app.add_systems(OnExit(AppState::GameInProgress), close_chat);
+    app.add_systems(OnExit(AppState::LobbyScreen), close_chat);
+    app.add_systems(
+        Update,
+        (
+            chat_toggle_interaction,
+            chat_keyboard_input,
+            refresh_chat_log.run_if(
+                on_event::<Stdb<OnInsert<ChatMessage>>>.or(on_event::<Stdb<OnInsert<Block>>>),
+            ),
+        )
+            .run_if(in_state(AppState::GameInProgress).or(in_state(AppState::LobbyScreen)))
+            .run_if(resource_exists::<ChatState>)
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+    app.add_systems(
+        Update,
+        (
+            reaction_button_interaction,
+            on_reaction_received.run_if(on_event::<Stdb<OnInsert<GameReaction>>>),
+            animate_reaction_bubbles,
+        )
+            .run_if(in_state(AppState::GameInProgress))
+            .run_if(resource_exists::<BoardData>)
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+}
@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::network::{
+    Emote, GameReaction, NetworkAuth, NetworkConnection, OnInsert, SendReaction, Stdb,
+};
+use crate::resources::{BoardData, FontSpaceGrotesk};
+use crate::ui::{UiButtonStyle, colors};
+
+use super::{MuteReactionsButton, ReactionBubble, ReactionButton, ReactionsMuted};
+
+// Matches the time the server keeps the reaction around
+const BUBBLE_LIFETIME_SECS: f32 = 3.;
+// How far a bubble floats up before it is gone
+const BUBBLE_RISE: f32 = 12.;
+const BUBBLE_TOP: f32 = 34.;
+
+/// The font has no emoji glyphs, the emotes are spelled out
+fn emote_label(emote: Emote) -> &'static str {
+    match emote {
+        Emote::ThumbsUp => "+1",
+        Emote::Wow => "Wow!",
+        Emote::GoodGame => "GG",
+        Emote::Hurry => "Hurry",
+    }
+}
+
+fn mute_label(muted: bool) -> &'static str {
+    if muted { "Unmute" } else { "Mute" }
+}
+
+/// Row of emote buttons at the top of the chat panel, only players get to react
+pub fn spawn_reaction_bar(parent: &mut ChildSpawnerCommands, font: &Handle<Font>, muted: bool) {
+    let reaction_button_style = UiButtonStyle {
+        color: colors::GOLD.into(),
+        border_color: colors::DEEP_PINK.into(),
+        text_color: colors::DARK_VIOLET.into(),
+    };
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.),
+            justify_content: JustifyContent::SpaceBetween,
+            margin: UiRect::bottom(Val::Px(4.)),
+            ..default()
+        })
+        .with_children(|bar| {
+            let buttons = [Emote::ThumbsUp, Emote::Wow, Emote::GoodGame, Emote::Hurry]
+                .map(|emote| (Some(emote), emote_label(emote)));
+            for (emote, label) in buttons.into_iter().chain([(None, mute_label(muted))]) {
+                let mut button = bar.spawn((
+                    Button,
+                    reaction_button_style,
+                    Node {
+                        height: Val::Px(18.0),
+                        min_width: Val::Px(28.),
+                        padding: UiRect::horizontal(Val::Px(2.)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    BorderRadius::all(Val::Px(4.0)),
+                    BorderColor(reaction_button_style.border_color),
+                    BackgroundColor(reaction_button_style.color),
+                ));
+                match emote {
+                    Some(emote) => button.insert(ReactionButton(emote)),
+                    None => button.insert(MuteReactionsButton),
+                };
+                button.with_child((
+                    Text::new(label),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 10.0,
+                        ..default()
+                    },
+                    TextColor(reaction_button_style.text_color),
+                ));
+            }
+        });
+}
+
+#[allow(clippy::type_complexity)]
+pub fn reaction_button_interaction(
+    mut interaction_query: Query<
+        (
+            Option<&ReactionButton>,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        (
+            Changed<Interaction>,
+            Or<(With<ReactionButton>, With<MuteReactionsButton>)>,
+        ),
+    >,
+    mut text_query: Query<(&mut Text, &mut TextColor)>,
+    mut muted: ResMut<ReactionsMuted>,
+    board: Res<BoardData>,
+    conn: Res<NetworkConnection>,
+) -> Result {
+    for (reaction, button_style, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let (mut text, mut text_color) = text_query.get_mut(children[0])?;
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = button_style.text_color.into();
+                match reaction {
+                    Some(ReactionButton(emote)) => {
+                        conn.reducers.send_reaction(board.id(), *emote)?;
+                    }
+                    None => {
+                        muted.0 = !muted.0;
+                        **text = mute_label(muted.0).to_string();
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *color = button_style.text_color.into();
+                *text_color = button_style.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = button_style.color.into();
+                *text_color = button_style.text_color.into();
+                *border_color = button_style.border_color.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Pops a bubble next to the turn label for every reaction of the other side
+pub fn on_reaction_received(
+    mut cmds: Commands,
+    mut reaction_ev: EventReader<Stdb<OnInsert<GameReaction>>>,
+    muted: Res<ReactionsMuted>,
+    board: Res<BoardData>,
+    network_auth: Res<NetworkAuth>,
+    font: Res<FontSpaceGrotesk>,
+) {
+    for Stdb(OnInsert(reaction)) in reaction_ev.read() {
+        if muted.0 || reaction.sender == network_auth.identity || reaction.game_id != board.id() {
+            continue;
+        }
+        cmds.spawn((
+            StateScoped(AppState::GameInProgress),
+            ReactionBubble(Timer::from_seconds(BUBBLE_LIFETIME_SECS, TimerMode::Once)),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(BUBBLE_TOP),
+                left: Val::Px(80.),
+                padding: UiRect::axes(Val::Px(5.), Val::Px(2.)),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(8.)),
+            BackgroundColor(Color::WHITE),
+            ZIndex(6),
+        ))
+        .with_child((
+            Text::new(emote_label(reaction.emote)),
+            TextFont {
+                font: font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(colors::DARK_VIOLET.into()),
+        ));
+    }
+}
+
+/// Bubbles pop in, float up and fade away
+pub fn animate_reaction_bubbles(
+    mut cmds: Commands,
+    mut bubble_q: Query<(
+        Entity,
+        &mut ReactionBubble,
+        &mut Node,
+        &mut Transform,
+        &mut BackgroundColor,
+        &Children,
+    )>,
+    mut text_color_q: Query<&mut TextColor>,
+    time: Res<Time>,
+) {
+    for (entity, mut bubble, mut node, mut transform, mut background, children) in &mut bubble_q {
+        bubble.0.tick(time.delta());
+        if bubble.0.finished() {
+            cmds.entity(entity).despawn();
+            continue;
+        }
+
+        let progress = bubble.0.fraction();
+        transform.scale = Vec3::splat((progress * 8.).min(1.));
+        node.top = Val::Px(BUBBLE_TOP - BUBBLE_RISE * progress);
+        let alpha = ((1. - progress) * 3.).min(1.);
+        background.0.set_alpha(alpha);
+        if let Ok(mut text_color) = text_color_q.get_mut(children[0]) {
+            text_color.0.set_alpha(alpha);
+        }
+    }
+}
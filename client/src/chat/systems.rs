@@ -0,0 +1,319 @@
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use spacetimedb_sdk::{DbContext, Table};
+
+use crate::AppState;
+use crate::network::{
+    BlockTableAccess, ChatMessage, ChatMessageTableAccess, NetworkAuth, NetworkConnection,
+    SendMessage,
+};
+use crate::resources::{BoardData, FontSpaceGrotesk};
+use crate::ui::{SCREEN_WIDTH, UiButtonStyle, colors, player_label};
+
+use super::{
+    CHAT_HISTORY, CHAT_PANEL_WIDTH, ChatInputLine, ChatLog, ChatPanel, ChatState, ChatToggleButton,
+    LOBBY_CHANNEL, MAX_MESSAGE_LENGTH, ReactionsMuted, spawn_reaction_bar,
+};
+
+/// Chat between the players and spectators of the network game on screen
+pub fn game_chat(
+    mut cmds: Commands,
+    font: Res<FontSpaceGrotesk>,
+    board: Res<BoardData>,
+    muted: Res<ReactionsMuted>,
+) {
+    if !board.is_network_game() {
+        cmds.remove_resource::<ChatState>();
+        return;
+    }
+    cmds.insert_resource(ChatState::new(board.id()));
+    // Spectators read along but don't react
+    let reactions = (!board.is_spectating()).then_some(muted.0);
+    spawn_chat(&mut cmds, &font, AppState::GameInProgress, reactions);
+}
+
+/// Chat open to everybody while waiting for an opponent
+pub fn lobby_chat(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
+    cmds.insert_resource(ChatState::new(LOBBY_CHANNEL));
+    spawn_chat(&mut cmds, &font, AppState::LobbyScreen, None);
+}
+
+/// `reactions` adds the emote buttons, holding whether the reactions are muted
+fn spawn_chat(cmds: &mut Commands, font: &Handle<Font>, state: AppState, reactions: Option<bool>) {
+    let toggle_button_style = UiButtonStyle {
+        color: colors::DODGER_BLUE.into(),
+        border_color: Color::WHITE,
+        text_color: Color::WHITE,
+    };
+    // Sits in the top bar, next to the leave button, clear of the grid
+    cmds.spawn((
+        StateScoped(state.clone()),
+        ChatToggleButton,
+        Button,
+        toggle_button_style,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(6.),
+            left: Val::Px(66.),
+            width: Val::Px(26.),
+            height: Val::Px(20.),
+            border: UiRect::all(Val::Px(1.0)),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        ZIndex(5),
+        BorderRadius::all(Val::Px(4.0)),
+        BorderColor(toggle_button_style.border_color),
+        BackgroundColor(toggle_button_style.color),
+    ))
+    .with_child((
+        Text::new("Chat"),
+        TextFont {
+            font: font.clone(),
+            font_size: 9.0,
+            ..default()
+        },
+        TextColor(toggle_button_style.text_color),
+    ));
+
+    // Beside the screen, in the room the window makes for it while open, the grid stays playable
+    cmds.spawn((
+        StateScoped(state),
+        ChatPanel,
+        Node {
+            display: Display::None,
+            position_type: PositionType::Absolute,
+            top: Val::Px(0.),
+            left: Val::Px(SCREEN_WIDTH),
+            width: Val::Px(CHAT_PANEL_WIDTH),
+            bottom: Val::Px(0.),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(4.)),
+            border: UiRect::left(Val::Px(1.)),
+            ..default()
+        },
+        BorderColor(colors::DODGER_BLUE.with_alpha(0.5).into()),
+        BackgroundColor(Color::BLACK.with_alpha(0.95)),
+        ZIndex(4),
+    ))
+    .with_children(|panel| {
+        if let Some(muted) = reactions {
+            spawn_reaction_bar(panel, font, muted);
+        }
+        panel.spawn((
+            ChatLog,
+            Node {
+                flex_grow: 1.,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(1.),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+        ));
+        panel.spawn((
+            ChatInputLine,
+            Text::new(""),
+            TextFont {
+                font: font.clone(),
+                font_size: 11.0,
+                ..default()
+            },
+            TextColor(Color::WHITE.with_alpha(0.5)),
+            Node {
+                border: UiRect::top(Val::Px(1.)),
+                padding: UiRect::top(Val::Px(2.)),
+                ..default()
+            },
+            BorderColor(colors::DODGER_BLUE.with_alpha(0.5).into()),
+        ));
+    });
+}
+
+#[allow(clippy::type_complexity)]
+pub fn chat_toggle_interaction(
+    mut cmds: Commands,
+    mut interaction_query: Query<
+        (
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        (Changed<Interaction>, With<ChatToggleButton>),
+    >,
+    mut text_query: Query<&mut TextColor>,
+    mut panel_q: Query<&mut Node, With<ChatPanel>>,
+    mut input_line_q: Query<&mut Text, With<ChatInputLine>>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut chat: ResMut<ChatState>,
+) -> Result {
+    for (toggle_button, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0])?;
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = toggle_button.text_color.into();
+
+                chat.open = !chat.open;
+                panel_q.single_mut()?.display = if chat.open {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+                fit_window(&mut window_q.single_mut()?, chat.open);
+                if chat.open {
+                    **input_line_q.single_mut()? = draft_line(&chat);
+                    cmds.run_system_cached(refresh_chat_log);
+                }
+            }
+            Interaction::Hovered => {
+                *color = toggle_button.text_color.into();
+                *text_color = toggle_button.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = toggle_button.color.into();
+                *text_color = toggle_button.text_color.into();
+                *border_color = toggle_button.border_color.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Types into the draft while the panel is open, Enter sends it
+pub fn chat_keyboard_input(
+    mut keyboard_input_ev: EventReader<KeyboardInput>,
+    mut input_line_q: Query<&mut Text, With<ChatInputLine>>,
+    mut chat: ResMut<ChatState>,
+    conn: Res<NetworkConnection>,
+) -> Result {
+    if !chat.open {
+        keyboard_input_ev.clear();
+        return Ok(());
+    }
+
+    let mut edited = false;
+    for input in keyboard_input_ev.read() {
+        if input.state != ButtonState::Pressed {
+            continue;
+        }
+        let room_left = chat.draft.chars().count() < MAX_MESSAGE_LENGTH;
+        match &input.logical_key {
+            Key::Enter if !chat.draft.trim().is_empty() => {
+                let text = std::mem::take(&mut chat.draft);
+                conn.reducers.send_message(chat.channel.clone(), text)?;
+            }
+            Key::Backspace => {
+                chat.draft.pop();
+            }
+            Key::Space if room_left => chat.draft.push(' '),
+            Key::Character(typed) if room_left => chat.draft.push_str(typed),
+            _ => continue,
+        }
+        edited = true;
+    }
+    if edited {
+        **input_line_q.single_mut()? = draft_line(&chat);
+    }
+    Ok(())
+}
+
+/// Rebuilds the latest messages of the chat's channel from the cache
+pub fn refresh_chat_log(
+    mut cmds: Commands,
+    mut chat_log_q: Query<(Entity, &mut ScrollPosition), With<ChatLog>>,
+    chat: Res<ChatState>,
+    connection: Res<NetworkConnection>,
+    network_auth: Res<NetworkAuth>,
+    font: Res<FontSpaceGrotesk>,
+) -> Result {
+    let (chat_log, mut scroll_position) = chat_log_q.single_mut()?;
+    let me = network_auth.identity;
+
+    let mut messages: Vec<ChatMessage> = connection
+        .db()
+        .chat_message()
+        .iter()
+        .filter(|m| m.channel == chat.channel)
+        .filter(|m| {
+            !connection
+                .db()
+                .block()
+                .iter()
+                .any(|b| b.blocked == m.sender)
+        })
+        .collect();
+    messages.sort_by_key(|m| m.id);
+    let skipped = messages.len().saturating_sub(CHAT_HISTORY);
+
+    cmds.entity(chat_log)
+        .despawn_related::<Children>()
+        .with_children(|log| {
+            for message in messages.into_iter().skip(skipped) {
+                let sender_color = if message.sender == me {
+                    colors::GREEN_YELLOW
+                } else {
+                    colors::GOLD
+                };
+                log.spawn((
+                    Text::new(format!("{}: ", player_label(&message.sender, &me))),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 11.0,
+                        ..default()
+                    },
+                    TextColor(sender_color.into()),
+                ))
+                .with_child((
+                    TextSpan::new(message.text),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 11.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            }
+        });
+
+    // Newest messages at the bottom
+    scroll_position.offset_y = 100000.;
+    Ok(())
+}
+
+/// Gives the window back its width once the screen with the chat is gone
+pub fn close_chat(
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    chat: Option<ResMut<ChatState>>,
+) -> Result {
+    if let Some(mut chat) = chat {
+        chat.open = false;
+    }
+    fit_window(&mut window_q.single_mut()?, false);
+    Ok(())
+}
+
+fn fit_window(window: &mut Window, chat_open: bool) {
+    let width = if chat_open {
+        SCREEN_WIDTH + CHAT_PANEL_WIDTH
+    } else {
+        SCREEN_WIDTH
+    };
+    let height = window.resolution.height();
+    window.resolution.set(width, height);
+}
+
+fn draft_line(chat: &ChatState) -> String {
+    if chat.draft.is_empty() {
+        "Type, Enter to send".to_string()
+    } else {
+        format!("> {}_", chat.draft)
+    }
+}
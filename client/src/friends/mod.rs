@@ -0,0 +1,67 @@
+mod systems;
+
+use bevy::prelude::*;
+use spacetimedb_sdk::Identity;
+
+use crate::AppState;
+use crate::network::{
+    Block, Friendship, Invitation, NetworkConnection, OnDelete, OnInsert, OnUpdate, Presence, Stdb,
+};
+
+pub use systems::*;
+
+// Nicknames are the last six hex characters of an identity, full identities are 64
+const MAX_TARGET_LENGTH: usize = 64;
+
+/// Nickname typed in to send a friend request
+#[derive(Resource, Default)]
+pub struct FriendDraft(pub String);
+
+#[derive(Component)]
+pub struct FriendsScreen;
+
+#[derive(Component)]
+pub struct FriendsPanel;
+
+#[derive(Component)]
+pub struct FriendInputLine;
+
+#[derive(Component, Clone)]
+pub enum FriendsButton {
+    Back,
+    Add,
+    Block,
+    Accept(u32),
+    Remove(u32),
+    Challenge(Identity),
+    Unblock(u32),
+}
+
+pub fn setup_systems(app: &mut App) {
+    app.add_systems(
+        OnEnter(AppState::FriendsScreen),
+        (friends_screen, refresh_friends_screen)
+            .chain()
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+    app.add_systems(
+        Update,
+        (
+            friends_button_interaction,
+            friend_keyboard_input,
+            refresh_friends_screen.run_if(
+                on_event::<Stdb<OnInsert<Friendship>>>
+                    .or(on_event::<Stdb<OnUpdate<Friendship>>>)
+                    .or(on_event::<Stdb<OnDelete<Friendship>>>)
+                    .or(on_event::<Stdb<OnInsert<Presence>>>)
+                    .or(on_event::<Stdb<OnUpdate<Presence>>>)
+                    .or(on_event::<Stdb<OnInsert<Invitation>>>)
+                    .or(on_event::<Stdb<OnDelete<Invitation>>>)
+                    .or(on_event::<Stdb<OnInsert<Block>>>)
+                    .or(on_event::<Stdb<OnDelete<Block>>>),
+            ),
+        )
+            .run_if(in_state(AppState::FriendsScreen))
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+}
@@ -0,0 +1,380 @@
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, Identity, Table};
+
+use crate::AppState;
+use crate::network::{
+    AcceptFriend, BlockPlayer, BlockTableAccess, ChallengePlayer, FriendRequest, Friendship,
+    FriendshipTableAccess, InvitationTableAccess, NetworkAuth, NetworkConnection, PresenceStatus,
+    PresenceTableAccess, RemoveFriend, SidePreference, UnblockPlayer,
+};
+use crate::resources::FontSpaceGrotesk;
+use crate::ui::{UiButtonStyle, colors, short_identity, spawn_small_button};
+
+use super::{
+    FriendDraft, FriendInputLine, FriendsButton, FriendsPanel, FriendsScreen, MAX_TARGET_LENGTH,
+};
+
+pub fn friends_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
+    cmds.insert_resource(FriendDraft::default());
+    cmds.spawn((
+        StateScoped(AppState::FriendsScreen),
+        FriendsScreen,
+        Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.),
+            padding: UiRect::top(Val::Px(6.)),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.975)),
+        ZIndex(2),
+    ))
+    .with_children(|parent| {
+        parent
+            .spawn(Node {
+                width: Val::Percent(96.),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                ..default()
+            })
+            .with_children(|top_bar| {
+                spawn_small_button(top_bar, &font, FriendsButton::Back, "Back");
+                top_bar.spawn((
+                    Label,
+                    Text::new("Friends"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(colors::GOLD.into()),
+                ));
+            });
+
+        parent
+            .spawn(Node {
+                width: Val::Percent(96.),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                ..default()
+            })
+            .with_children(|add_bar| {
+                add_bar.spawn((
+                    FriendInputLine,
+                    Text::new(draft_line("")),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 11.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE.with_alpha(0.7)),
+                ));
+                spawn_small_button(add_bar, &font, FriendsButton::Add, "Add");
+                spawn_small_button(add_bar, &font, FriendsButton::Block, "Block");
+            });
+
+        parent.spawn((
+            FriendsPanel,
+            Node {
+                width: Val::Percent(96.),
+                height: Val::Percent(75.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.),
+                overflow: Overflow::scroll_y(),
+                border: UiRect::top(Val::Px(1.)),
+                ..default()
+            },
+            BorderColor(colors::DODGER_BLUE.with_alpha(0.5).into()),
+        ));
+    });
+}
+
+/// Rebuilds the friends list from the cache, the ones who can play first
+pub fn refresh_friends_screen(
+    mut cmds: Commands,
+    friends_panel_q: Query<Entity, With<FriendsPanel>>,
+    connection: Res<NetworkConnection>,
+    network_auth: Res<NetworkAuth>,
+    font: Res<FontSpaceGrotesk>,
+) -> Result {
+    let friends_panel = friends_panel_q.single()?;
+    let me = network_auth.identity;
+
+    let mut friends: Vec<(Friendship, Identity, String, PresenceStatus)> = connection
+        .db()
+        .friendship()
+        .iter()
+        .filter(|f| f.requester == me || f.addressee == me)
+        .map(|f| {
+            let friend = if f.requester == me {
+                f.addressee
+            } else {
+                f.requester
+            };
+            let (nickname, status) = connection
+                .db()
+                .presence()
+                .identity()
+                .find(&friend)
+                .map_or((short_identity(&friend), PresenceStatus::Offline), |p| {
+                    (p.nickname, p.status)
+                });
+            (f, friend, nickname, status)
+        })
+        .collect();
+    friends.sort_by(|a, b| {
+        (a.3 == PresenceStatus::Offline, &a.2).cmp(&(b.3 == PresenceStatus::Offline, &b.2))
+    });
+
+    let blocks: Vec<(u32, String)> = connection
+        .db()
+        .block()
+        .iter()
+        .filter(|b| b.blocker == me)
+        .map(|b| {
+            let nickname = connection
+                .db()
+                .presence()
+                .identity()
+                .find(&b.blocked)
+                .map_or(short_identity(&b.blocked), |p| p.nickname);
+            (b.id, nickname)
+        })
+        .collect();
+
+    cmds.entity(friends_panel)
+        .despawn_related::<Children>()
+        .with_children(|list| {
+            if friends.is_empty() && blocks.is_empty() {
+                list.spawn((
+                    Label,
+                    Text::new("Add a friend by nickname"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(colors::DODGER_BLUE.into()),
+                ));
+            }
+
+            for (friendship, friend, nickname, status) in friends {
+                let (status_label, status_color) = if !friendship.accepted {
+                    if friendship.addressee == me {
+                        ("Wants to be friends", colors::GOLD)
+                    } else {
+                        ("Request sent", colors::DODGER_BLUE)
+                    }
+                } else {
+                    match status {
+                        PresenceStatus::Online => ("Online", colors::GREEN_YELLOW),
+                        PresenceStatus::InLobby => ("In the lobby", colors::GOLD),
+                        PresenceStatus::InGame => ("Playing", colors::DODGER_BLUE),
+                        PresenceStatus::Offline => ("Offline", colors::DEEP_PINK),
+                    }
+                };
+                let challenged = connection
+                    .db()
+                    .invitation()
+                    .iter()
+                    .any(|i| i.sender == me && i.recipient == friend);
+
+                list.spawn((
+                    Node {
+                        width: Val::Percent(100.),
+                        min_height: Val::Px(30.),
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(2.),
+                        border: UiRect::bottom(Val::Px(1.)),
+                        ..default()
+                    },
+                    BorderColor(colors::DODGER_BLUE.with_alpha(0.2).into()),
+                ))
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(nickname),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(colors::GOLD.into()),
+                        Node {
+                            flex_grow: 1.,
+                            ..default()
+                        },
+                    ))
+                    .with_child((
+                        TextSpan::new(format!("\n{status_label}")),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 10.0,
+                            ..default()
+                        },
+                        TextColor(status_color.into()),
+                    ));
+
+                    let can_play =
+                        matches!(status, PresenceStatus::Online | PresenceStatus::InLobby);
+                    if friendship.accepted && can_play && !challenged {
+                        spawn_small_button(row, &font, FriendsButton::Challenge(friend), "Duel");
+                    } else if !friendship.accepted && friendship.addressee == me {
+                        spawn_small_button(row, &font, FriendsButton::Accept(friendship.id), "Ok");
+                    }
+                    spawn_small_button(row, &font, FriendsButton::Remove(friendship.id), "x");
+                });
+            }
+
+            // Blocked players last, they can be let back in from here
+            for (block_id, nickname) in blocks {
+                list.spawn((
+                    Node {
+                        width: Val::Percent(100.),
+                        min_height: Val::Px(24.),
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        border: UiRect::bottom(Val::Px(1.)),
+                        ..default()
+                    },
+                    BorderColor(colors::DEEP_PINK.with_alpha(0.2).into()),
+                ))
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(format!("{nickname} blocked")),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(colors::DEEP_PINK.into()),
+                    ));
+                    spawn_small_button(row, &font, FriendsButton::Unblock(block_id), "Unblock");
+                });
+            }
+        });
+    Ok(())
+}
+
+#[allow(clippy::type_complexity)]
+pub fn friends_button_interaction(
+    mut interaction_query: Query<
+        (
+            &FriendsButton,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut TextColor>,
+    mut input_line_q: Query<&mut Text, With<FriendInputLine>>,
+    mut draft: ResMut<FriendDraft>,
+    mut game_state: ResMut<NextState<AppState>>,
+    conn: Res<NetworkConnection>,
+) -> Result {
+    for (friends_button, button_style, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0])?;
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = button_style.text_color.into();
+                match friends_button {
+                    FriendsButton::Back => game_state.set(AppState::HomeScreen),
+                    FriendsButton::Add => {
+                        send_friend_request(&conn, &mut draft)?;
+                        **input_line_q.single_mut()? = draft_line(&draft.0);
+                    }
+                    FriendsButton::Block => {
+                        if !draft.0.is_empty() {
+                            conn.reducers.block_player(std::mem::take(&mut draft.0))?;
+                        }
+                        **input_line_q.single_mut()? = draft_line(&draft.0);
+                    }
+                    FriendsButton::Unblock(block_id) => {
+                        conn.reducers.unblock_player(*block_id)?;
+                    }
+                    FriendsButton::Accept(friendship_id) => {
+                        conn.reducers.accept_friend(*friendship_id)?;
+                    }
+                    FriendsButton::Remove(friendship_id) => {
+                        conn.reducers.remove_friend(*friendship_id)?;
+                    }
+                    // The game opens from any screen once the friend accepts
+                    FriendsButton::Challenge(friend) => {
+                        conn.reducers.challenge_player(
+                            friend.to_string(),
+                            SidePreference::Random,
+                            true,
+                        )?;
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *color = button_style.text_color.into();
+                *text_color = button_style.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = button_style.color.into();
+                *text_color = button_style.text_color.into();
+                *border_color = button_style.border_color.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Types the nickname of the friend to add, Enter sends the request
+pub fn friend_keyboard_input(
+    mut keyboard_input_ev: EventReader<KeyboardInput>,
+    mut input_line_q: Query<&mut Text, With<FriendInputLine>>,
+    mut draft: ResMut<FriendDraft>,
+    conn: Res<NetworkConnection>,
+) -> Result {
+    let mut edited = false;
+    for input in keyboard_input_ev.read() {
+        if input.state != ButtonState::Pressed {
+            continue;
+        }
+        match &input.logical_key {
+            Key::Enter => send_friend_request(&conn, &mut draft)?,
+            Key::Backspace => {
+                draft.0.pop();
+            }
+            Key::Character(typed) if draft.0.len() < MAX_TARGET_LENGTH => {
+                draft.0.push_str(typed.trim());
+            }
+            _ => continue,
+        }
+        edited = true;
+    }
+    if edited {
+        **input_line_q.single_mut()? = draft_line(&draft.0);
+    }
+    Ok(())
+}
+
+fn send_friend_request(conn: &NetworkConnection, draft: &mut FriendDraft) -> Result {
+    if draft.0.is_empty() {
+        return Ok(());
+    }
+    conn.reducers.friend_request(std::mem::take(&mut draft.0))?;
+    Ok(())
+}
+
+fn draft_line(draft: &str) -> String {
+    if draft.is_empty() {
+        "Type a nickname".to_string()
+    } else {
+        format!("> {draft}_")
+    }
+}
@@ -0,0 +1,30 @@
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::network::{Invitation, NetworkConnection, OnDelete, OnInsert, Stdb};
+
+pub use systems::*;
+
+/// Incoming challenge, shown on top of whatever screen is up
+#[derive(Component)]
+pub struct InvitationToast(pub u32);
+
+#[derive(Component)]
+pub struct InvitationButton {
+    pub invitation_id: u32,
+    pub accept: bool,
+}
+
+pub fn setup_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            on_invitation_received.run_if(on_event::<Stdb<OnInsert<Invitation>>>),
+            on_invitation_removed.run_if(on_event::<Stdb<OnDelete<Invitation>>>),
+            invitation_button_interaction,
+            hide_accept_during_game,
+        )
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+}
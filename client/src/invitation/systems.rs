@@ -0,0 +1,199 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::network::{
+    AcceptInvitation, DeclineInvitation, Invitation, NetworkAuth, NetworkConnection, OnDelete,
+    OnInsert, Stdb,
+};
+use crate::resources::FontSpaceGrotesk;
+use crate::ui::{UiButtonStyle, colors};
+
+use super::{InvitationButton, InvitationToast};
+
+/// Pops a toast for every challenge this client receives
+pub fn on_invitation_received(
+    mut cmds: Commands,
+    mut invitation_ev: EventReader<Stdb<OnInsert<Invitation>>>,
+    network_auth: Res<NetworkAuth>,
+    font: Res<FontSpaceGrotesk>,
+) {
+    let accept_button_style = UiButtonStyle {
+        color: colors::GREEN_YELLOW.into(),
+        border_color: colors::DODGER_BLUE.into(),
+        text_color: colors::DARK_VIOLET.into(),
+    };
+    let decline_button_style = UiButtonStyle {
+        color: colors::GOLD.into(),
+        border_color: colors::DEEP_PINK.into(),
+        text_color: colors::DARK_VIOLET.into(),
+    };
+
+    for Stdb(OnInsert(invitation)) in invitation_ev.read() {
+        if invitation.recipient != network_auth.identity {
+            continue;
+        }
+        let rules = if invitation.rated { "rated" } else { "casual" };
+        cmds.spawn((
+            InvitationToast(invitation.id),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(64.),
+                left: Val::Px(4.),
+                right: Val::Px(4.),
+                padding: UiRect::all(Val::Px(4.)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.),
+                border: UiRect::all(Val::Px(1.)),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(6.)),
+            BorderColor(colors::GOLD.into()),
+            BackgroundColor(colors::DARK_VIOLET.into()),
+            GlobalZIndex(10),
+        ))
+        .with_children(|toast| {
+            toast.spawn((
+                Label,
+                Text::new(format!(
+                    "{} ({}) challenges you, {rules}",
+                    invitation.sender_nickname, invitation.sender_rating
+                )),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(colors::GOLD.into()),
+            ));
+            toast
+                .spawn(Node {
+                    column_gap: Val::Px(8.),
+                    ..default()
+                })
+                .with_children(|buttons| {
+                    for (accept, label, button_style) in [
+                        (true, "Accept", accept_button_style),
+                        (false, "Decline", decline_button_style),
+                    ] {
+                        buttons
+                            .spawn((
+                                InvitationButton {
+                                    invitation_id: invitation.id,
+                                    accept,
+                                },
+                                Button,
+                                button_style,
+                                Node {
+                                    height: Val::Px(20.0),
+                                    min_width: Val::Px(56.),
+                                    border: UiRect::all(Val::Px(1.0)),
+                                    align_items: AlignItems::Center,
+                                    justify_content: JustifyContent::Center,
+                                    ..default()
+                                },
+                                BorderRadius::all(Val::Px(4.0)),
+                                BorderColor(button_style.border_color),
+                                BackgroundColor(button_style.color),
+                            ))
+                            .with_child((
+                                Text::new(label),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(button_style.text_color),
+                            ));
+                    }
+                });
+        });
+    }
+}
+
+/// Answered, cancelled or expired, the toast goes away
+pub fn on_invitation_removed(
+    mut cmds: Commands,
+    mut invitation_del_ev: EventReader<Stdb<OnDelete<Invitation>>>,
+    toast_q: Query<(Entity, &InvitationToast)>,
+) {
+    for Stdb(OnDelete(invitation)) in invitation_del_ev.read() {
+        for (entity, toast) in &toast_q {
+            if toast.0 == invitation.id {
+                cmds.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn invitation_button_interaction(
+    mut interaction_query: Query<
+        (
+            &InvitationButton,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut TextColor>,
+    conn: Res<NetworkConnection>,
+) -> Result {
+    for (invitation_button, button_style, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0])?;
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = button_style.text_color.into();
+                // The game shows up through `on_game_created` once accepted
+                if invitation_button.accept {
+                    conn.reducers
+                        .accept_invitation(invitation_button.invitation_id)?;
+                } else {
+                    conn.reducers
+                        .decline_invitation(invitation_button.invitation_id)?;
+                }
+            }
+            Interaction::Hovered => {
+                *color = button_style.text_color.into();
+                *text_color = button_style.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = button_style.color.into();
+                *text_color = button_style.text_color.into();
+                *border_color = button_style.border_color.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A challenge can only be turned down while a game is on screen
+pub fn hide_accept_during_game(
+    mut accept_button_q: Query<(&InvitationButton, &mut Node)>,
+    game_state: Res<State<AppState>>,
+) {
+    let in_game = matches!(
+        game_state.get(),
+        AppState::GameSetup | AppState::GameInProgress
+    );
+    for (invitation_button, mut node) in &mut accept_button_q {
+        if !invitation_button.accept {
+            continue;
+        }
+        let display = if in_game {
+            Display::None
+        } else {
+            Display::Flex
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
@@ -0,0 +1,55 @@
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::network::{JoinRequest, NetworkConnection, OnDelete, OnInsert, Stdb};
+use crate::ui::lobby_room_screen;
+
+pub use systems::*;
+
+/// Incoming requests, listed on the owner's waiting screen
+#[derive(Component)]
+pub struct JoinRequestPanel;
+
+#[derive(Component)]
+pub struct JoinRequestButton {
+    pub request_id: u32,
+    pub accept: bool,
+}
+
+/// Short message on the home screen about a request that didn't go through
+#[derive(Component)]
+pub struct JoinRequestNotice(pub Timer);
+
+pub fn setup_systems(app: &mut App) {
+    app.add_systems(
+        OnEnter(AppState::LobbyScreen),
+        (spawn_join_request_panel, refresh_join_requests)
+            .chain()
+            .after(lobby_room_screen)
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+    app.add_systems(
+        Update,
+        (
+            join_request_button_interaction,
+            refresh_join_requests.run_if(
+                on_event::<Stdb<OnInsert<JoinRequest>>>.or(on_event::<Stdb<OnDelete<JoinRequest>>>),
+            ),
+        )
+            .run_if(in_state(AppState::LobbyScreen))
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+    app.add_systems(
+        Update,
+        (
+            on_join_request_changed.run_if(
+                on_event::<Stdb<OnInsert<JoinRequest>>>.or(on_event::<Stdb<OnDelete<JoinRequest>>>),
+            ),
+            expire_join_request_notices,
+        )
+            .run_if(in_state(AppState::HomeScreen))
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+}
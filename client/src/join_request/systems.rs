@@ -0,0 +1,253 @@
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, Table};
+
+use crate::AppState;
+use crate::network::{
+    AcceptJoinRequest, DeclineJoinRequest, GameTableAccess, JoinRequest, JoinRequestTableAccess,
+    LobbyRoomTableAccess, NetworkAuth, NetworkConnection, OnDelete, Stdb,
+};
+use crate::resources::FontSpaceGrotesk;
+use crate::ui::{LobbyRoomScreen, UiButtonStyle, colors, populate_lobby_from_cache};
+
+use super::{JoinRequestButton, JoinRequestNotice, JoinRequestPanel};
+
+const NOTICE_SECS: f32 = 3.;
+
+/// Adds the list of incoming requests right below "Awaiting opponent"
+pub fn spawn_join_request_panel(
+    mut cmds: Commands,
+    lobby_room_screen_q: Query<Entity, With<LobbyRoomScreen>>,
+) -> Result {
+    let lobby_room_screen = lobby_room_screen_q.single()?;
+    let panel = cmds
+        .spawn((
+            JoinRequestPanel,
+            Node {
+                width: Val::Percent(90.),
+                max_height: Val::Px(48.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+        ))
+        .id();
+    cmds.entity(lobby_room_screen).insert_children(1, &[panel]);
+    Ok(())
+}
+
+/// Lists who asked to join the owner's room, oldest first
+pub fn refresh_join_requests(
+    mut cmds: Commands,
+    panel_q: Query<Entity, With<JoinRequestPanel>>,
+    connection: Res<NetworkConnection>,
+    network_auth: Res<NetworkAuth>,
+    font: Res<FontSpaceGrotesk>,
+) -> Result {
+    let panel = panel_q.single()?;
+    cmds.entity(panel).despawn_related::<Children>();
+
+    let Some(room) = connection
+        .db()
+        .lobby_room()
+        .owner()
+        .find(&network_auth.identity)
+    else {
+        return Ok(());
+    };
+    let mut requests: Vec<JoinRequest> = connection
+        .db()
+        .join_request()
+        .iter()
+        .filter(|r| r.room_id == room.id)
+        .collect();
+    requests.sort_by_key(|r| r.requested_at);
+
+    let accept_button_style = UiButtonStyle {
+        color: colors::GREEN_YELLOW.into(),
+        border_color: colors::DODGER_BLUE.into(),
+        text_color: colors::DARK_VIOLET.into(),
+    };
+    let decline_button_style = UiButtonStyle {
+        color: colors::GOLD.into(),
+        border_color: colors::DEEP_PINK.into(),
+        text_color: colors::DARK_VIOLET.into(),
+    };
+    cmds.entity(panel).with_children(|list| {
+        for request in requests {
+            list.spawn(Node {
+                width: Val::Percent(100.),
+                height: Val::Px(20.),
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(4.),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Label,
+                    Text::new(format!(
+                        "{} {}",
+                        request.challenger_nickname, request.challenger_rating
+                    )),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(colors::GOLD.into()),
+                    Node {
+                        flex_grow: 1.,
+                        ..default()
+                    },
+                ));
+                for (accept, label, button_style) in [
+                    (true, "Ok", accept_button_style),
+                    (false, "No", decline_button_style),
+                ] {
+                    row.spawn((
+                        JoinRequestButton {
+                            request_id: request.id,
+                            accept,
+                        },
+                        Button,
+                        button_style,
+                        Node {
+                            height: Val::Px(18.0),
+                            min_width: Val::Px(28.),
+                            border: UiRect::all(Val::Px(1.0)),
+                            align_items: AlignItems::Center,
+                            justify_content: JustifyContent::Center,
+                            ..default()
+                        },
+                        BorderRadius::all(Val::Px(4.0)),
+                        BorderColor(button_style.border_color),
+                        BackgroundColor(button_style.color),
+                    ))
+                    .with_child((
+                        Text::new(label),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(button_style.text_color),
+                    ));
+                }
+            });
+        }
+    });
+    Ok(())
+}
+
+#[allow(clippy::type_complexity)]
+pub fn join_request_button_interaction(
+    mut interaction_query: Query<
+        (
+            &JoinRequestButton,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut TextColor>,
+    conn: Res<NetworkConnection>,
+) -> Result {
+    for (request_button, button_style, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0])?;
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = button_style.text_color.into();
+                if request_button.accept {
+                    conn.reducers
+                        .accept_join_request(request_button.request_id)?;
+                } else {
+                    conn.reducers
+                        .decline_join_request(request_button.request_id)?;
+                }
+            }
+            Interaction::Hovered => {
+                *color = button_style.text_color.into();
+                *text_color = button_style.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = button_style.color.into();
+                *text_color = button_style.text_color.into();
+                *border_color = button_style.border_color.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Refreshes the "Sent" labels of the lobby, and tells the challenger when a request fell through
+pub fn on_join_request_changed(
+    mut cmds: Commands,
+    mut join_request_del_ev: EventReader<Stdb<OnDelete<JoinRequest>>>,
+    connection: Res<NetworkConnection>,
+    network_auth: Res<NetworkAuth>,
+    font: Res<FontSpaceGrotesk>,
+) {
+    cmds.run_system_cached(populate_lobby_from_cache);
+
+    for Stdb(OnDelete(request)) in join_request_del_ev.read() {
+        if request.challenger != network_auth.identity {
+            continue;
+        }
+        // Accepted, the game is about to show up
+        let me = network_auth.identity;
+        if connection
+            .db()
+            .game()
+            .iter()
+            .any(|g| g.x_player == me || g.o_player == me)
+        {
+            continue;
+        }
+        let notice = match connection.db().lobby_room().id().find(&request.room_id) {
+            Some(room) => format!("{} declined", room.owner_nickname),
+            None => "Room closed".to_string(),
+        };
+
+        cmds.spawn((
+            StateScoped(AppState::HomeScreen),
+            JoinRequestNotice(Timer::from_seconds(NOTICE_SECS, TimerMode::Once)),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(100.),
+                width: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ZIndex(4),
+        ))
+        .with_child((
+            Text::new(notice),
+            TextFont {
+                font: font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(colors::DEEP_PINK.into()),
+            BackgroundColor(Color::BLACK.with_alpha(0.8)),
+        ));
+    }
+}
+
+pub fn expire_join_request_notices(
+    mut cmds: Commands,
+    mut notice_q: Query<(Entity, &mut JoinRequestNotice)>,
+    time: Res<Time>,
+) {
+    for (entity, mut notice) in &mut notice_q {
+        if notice.0.tick(time.delta()).finished() {
+            cmds.entity(entity).despawn();
+        }
+    }
+}
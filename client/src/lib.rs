@@ -1,5 +1,16 @@
+mod arena;
+mod chat;
+mod friends;
+mod invitation;
+mod join_request;
+mod live_games;
 mod network;
+mod offline;
+mod rematch;
+mod report;
 mod resources;
+mod toast;
+mod tournament;
 mod ui;
 
 use bevy::prelude::*;
@@ -15,6 +26,10 @@ enum AppState {
     GameSetup,
     GameInProgress,
     GameOverScreen,
+    TournamentScreen,
+    ArenaScreen,
+    LiveGamesScreen,
+    FriendsScreen,
 }
 
 pub struct TicTacToe;
@@ -32,6 +47,17 @@ impl Plugin for TicTacToe {
         );
 
         network::setup_systems(app);
+        offline::setup_systems(app);
+        tournament::setup_systems(app);
+        arena::setup_systems(app);
+        live_games::setup_systems(app);
+        chat::setup_systems(app);
+        join_request::setup_systems(app);
+        invitation::setup_systems(app);
+        friends::setup_systems(app);
+        rematch::setup_systems(app);
+        report::setup_systems(app);
+        toast::setup_systems(app);
         ui::setup_systems(app);
     }
 }
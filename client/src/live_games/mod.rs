@@ -0,0 +1,40 @@
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::network::{LiveGameSummary, NetworkConnection, OnDelete, OnInsert, OnUpdate, Stdb};
+
+pub use systems::*;
+
+#[derive(Component)]
+pub struct LiveGamesScreen;
+
+#[derive(Component)]
+pub struct LiveGamesPanel;
+
+#[derive(Component, Clone)]
+pub enum LiveGamesButton {
+    Back,
+    Watch(String),
+}
+
+pub fn setup_systems(app: &mut App) {
+    app.add_systems(
+        OnEnter(AppState::LiveGamesScreen),
+        (live_games_screen, refresh_live_games_screen).chain(),
+    );
+    app.add_systems(
+        Update,
+        (
+            live_games_button_interaction,
+            refresh_live_games_screen.run_if(
+                on_event::<Stdb<OnInsert<LiveGameSummary>>>
+                    .or(on_event::<Stdb<OnUpdate<LiveGameSummary>>>)
+                    .or(on_event::<Stdb<OnDelete<LiveGameSummary>>>),
+            ),
+        )
+            .run_if(in_state(AppState::LiveGamesScreen))
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+}
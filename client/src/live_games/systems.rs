@@ -0,0 +1,213 @@
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, Table};
+
+use crate::AppState;
+use crate::network::{LiveGameSummary, LiveGameSummaryTableAccess, NetworkConnection, WatchGame};
+use crate::resources::FontSpaceGrotesk;
+use crate::ui::{UiButtonStyle, colors, spawn_small_button};
+
+use super::{LiveGamesButton, LiveGamesPanel, LiveGamesScreen};
+
+pub fn live_games_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
+    cmds.spawn((
+        StateScoped(AppState::LiveGamesScreen),
+        LiveGamesScreen,
+        Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.),
+            padding: UiRect::top(Val::Px(6.)),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.975)),
+        ZIndex(2),
+    ))
+    .with_children(|parent| {
+        parent
+            .spawn(Node {
+                width: Val::Percent(96.),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                ..default()
+            })
+            .with_children(|top_bar| {
+                spawn_small_button(top_bar, &font, LiveGamesButton::Back, "Back");
+                top_bar.spawn((
+                    Label,
+                    Text::new("Live games"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(colors::GOLD.into()),
+                ));
+            });
+
+        parent.spawn((
+            LiveGamesPanel,
+            Node {
+                width: Val::Percent(96.),
+                height: Val::Percent(85.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.),
+                overflow: Overflow::scroll_y(),
+                border: UiRect::top(Val::Px(1.)),
+                ..default()
+            },
+            BorderColor(colors::DODGER_BLUE.with_alpha(0.5).into()),
+        ));
+    });
+}
+
+/// Rebuilds the list of ongoing games from the cache
+pub fn refresh_live_games_screen(
+    mut cmds: Commands,
+    live_games_panel_q: Query<Entity, With<LiveGamesPanel>>,
+    connection: Res<NetworkConnection>,
+    font: Res<FontSpaceGrotesk>,
+) -> Result {
+    let live_games_panel = live_games_panel_q.single()?;
+
+    let mut live_games: Vec<LiveGameSummary> = connection.db().live_game_summary().iter().collect();
+    // The most watched games first
+    live_games.sort_by(|a, b| {
+        b.spectators
+            .cmp(&a.spectators)
+            .then_with(|| a.game_id.cmp(&b.game_id))
+    });
+
+    cmds.entity(live_games_panel)
+        .despawn_related::<Children>()
+        .with_children(|list| {
+            if live_games.is_empty() {
+                list.spawn((
+                    Label,
+                    Text::new("No games being played"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(colors::DODGER_BLUE.into()),
+                ));
+            }
+
+            for live_game in live_games {
+                list.spawn((
+                    Node {
+                        width: Val::Percent(100.),
+                        min_height: Val::Px(30.),
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        border: UiRect::bottom(Val::Px(1.)),
+                        ..default()
+                    },
+                    BorderColor(colors::DODGER_BLUE.with_alpha(0.2).into()),
+                ))
+                .with_children(|row| {
+                    row.spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    })
+                    .with_children(|details| {
+                        details
+                            .spawn((
+                                Text::default(),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                            ))
+                            .with_children(|players| {
+                                for (span, color) in [
+                                    (live_game.x_nickname.clone(), colors::GOLD),
+                                    (" v ".to_string(), colors::DODGER_BLUE),
+                                    (live_game.o_nickname.clone(), colors::DEEP_PINK),
+                                ] {
+                                    players.spawn((
+                                        TextSpan::new(span),
+                                        TextFont {
+                                            font: font.clone(),
+                                            font_size: 12.0,
+                                            ..default()
+                                        },
+                                        TextColor(color.into()),
+                                    ));
+                                }
+                            });
+                        details.spawn((
+                            Label,
+                            Text::new(format!(
+                                "{} T{} {} watching",
+                                live_game.variant, live_game.turn, live_game.spectators
+                            )),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 10.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE.with_alpha(0.7)),
+                        ));
+                    });
+                    spawn_small_button(
+                        row,
+                        &font,
+                        LiveGamesButton::Watch(live_game.game_id.clone()),
+                        "Watch",
+                    );
+                });
+            }
+        });
+    Ok(())
+}
+
+#[allow(clippy::type_complexity)]
+pub fn live_games_button_interaction(
+    mut interaction_query: Query<
+        (
+            &LiveGamesButton,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut TextColor>,
+    mut game_state: ResMut<NextState<AppState>>,
+    conn: Res<NetworkConnection>,
+) {
+    for (live_games_button, button_style, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = button_style.text_color.into();
+                match live_games_button {
+                    LiveGamesButton::Back => game_state.set(AppState::HomeScreen),
+                    // The board opens once the watched game reaches the cache
+                    LiveGamesButton::Watch(game_id) => {
+                        conn.reducers.watch_game(game_id.clone()).unwrap();
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *color = button_style.text_color.into();
+                *text_color = button_style.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = button_style.color.into();
+                *text_color = button_style.text_color.into();
+                *border_color = button_style.border_color.into();
+            }
+        }
+    }
+}
@@ -4,13 +4,21 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::side_preference_type::SidePreference;
+
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
-pub(super) struct CreateRoomArgs {}
+pub(super) struct CreateRoomArgs {
+    pub owner_side: SidePreference,
+    pub rated: bool,
+}
 
 impl From<CreateRoomArgs> for super::Reducer {
     fn from(args: CreateRoomArgs) -> Self {
-        Self::CreateRoom
+        Self::CreateRoom {
+            owner_side: args.owner_side,
+            rated: args.rated,
+        }
     }
 }
 
@@ -30,7 +38,7 @@ pub trait create_room {
     /// This method returns immediately, and errors only if we are unable to send the request.
     /// The reducer will run asynchronously in the future,
     ///  and its status can be observed by listening for [`Self::on_create_room`] callbacks.
-    fn create_room(&self) -> __sdk::Result<()>;
+    fn create_room(&self, owner_side: SidePreference, rated: bool) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `create_room`.
     ///
     /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
@@ -40,7 +48,7 @@ pub trait create_room {
     /// to cancel the callback.
     fn on_create_room(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext) + Send + 'static,
+        callback: impl FnMut(&super::ReducerEventContext, &SidePreference, &bool) + Send + 'static,
     ) -> CreateRoomCallbackId;
     /// Cancel a callback previously registered by [`Self::on_create_room`],
     /// causing it not to run in the future.
@@ -48,12 +56,13 @@ pub trait create_room {
 }
 
 impl create_room for super::RemoteReducers {
-    fn create_room(&self) -> __sdk::Result<()> {
-        self.imp.call_reducer("create_room", CreateRoomArgs {})
+    fn create_room(&self, owner_side: SidePreference, rated: bool) -> __sdk::Result<()> {
+        self.imp
+            .call_reducer("create_room", CreateRoomArgs { owner_side, rated })
     }
     fn on_create_room(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext) + Send + 'static,
+        mut callback: impl FnMut(&super::ReducerEventContext, &SidePreference, &bool) + Send + 'static,
     ) -> CreateRoomCallbackId {
         CreateRoomCallbackId(self.imp.on_reducer(
             "create_room",
@@ -61,7 +70,7 @@ impl create_room for super::RemoteReducers {
                 let super::ReducerEventContext {
                     event:
                         __sdk::ReducerEvent {
-                            reducer: super::Reducer::CreateRoom {},
+                            reducer: super::Reducer::CreateRoom { owner_side, rated },
                             ..
                         },
                     ..
@@ -69,7 +78,7 @@ impl create_room for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx)
+                callback(ctx, owner_side, rated)
             }),
         ))
     }
@@ -2,6 +2,7 @@
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
 #![allow(unused, clippy::all)]
+use super::bot_difficulty_type::BotDifficulty;
 use super::game_state_type::GameState;
 use super::game_type::Game;
 use super::player_type::Player;
@@ -4,6 +4,7 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::bot_difficulty_type::BotDifficulty;
 use super::game_state_type::GameState;
 use super::player_type::Player;
 
@@ -19,6 +20,11 @@ pub struct Game {
     pub state: GameState,
     pub turn: u8,
     pub time_expired: bool,
+    pub last_move: Option<u16>,
+    pub moves: Vec<u16>,
+    pub winning_mask: Option<u16>,
+    pub bot_difficulty: Option<BotDifficulty>,
+    pub rated: bool,
 }
 
 impl __sdk::InModule for Game {
@@ -3,6 +3,7 @@
 
 #![allow(unused, clippy::all)]
 use super::lobby_room_type::LobbyRoom;
+use super::side_preference_type::SidePreference;
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
 /// Table handle for the table `lobby_room`.
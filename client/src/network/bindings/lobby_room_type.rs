@@ -4,12 +4,22 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::side_preference_type::SidePreference;
+
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub struct LobbyRoom {
     pub id: u32,
     pub game_id: String,
     pub owner: __sdk::Identity,
+    pub owner_side: SidePreference,
+    pub owner_nickname: String,
+    pub owner_rating: u32,
+    pub variant: String,
+    pub time_control: String,
+    pub rated: bool,
+    pub created_at: __sdk::Timestamp,
+    pub expires_at: __sdk::Timestamp,
 }
 
 impl __sdk::InModule for LobbyRoom {
@@ -4,26 +4,227 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod accept_friend_reducer;
+pub mod accept_invitation_reducer;
+pub mod accept_join_request_reducer;
+pub mod accept_rematch_reducer;
+pub mod admin_close_room_reducer;
+pub mod admin_set_room_ttl_reducer;
+pub mod admin_table;
+pub mod admin_type;
+pub mod arena_end_schedule_table;
+pub mod arena_end_schedule_type;
+pub mod arena_pairing_schedule_table;
+pub mod arena_pairing_schedule_type;
+pub mod arena_player_table;
+pub mod arena_player_type;
+pub mod arena_table;
+pub mod arena_type;
+pub mod ban_player_reducer;
+pub mod ban_table;
+pub mod ban_type;
+pub mod block_player_reducer;
+pub mod block_table;
+pub mod block_type;
+pub mod bot_difficulty_type;
+pub mod bot_turn_schedule_table;
+pub mod bot_turn_schedule_type;
+pub mod cancel_invitation_reducer;
+pub mod challenge_player_reducer;
+pub mod chat_message_table;
+pub mod chat_message_type;
+pub mod create_arena_reducer;
 pub mod create_room_reducer;
+pub mod create_tournament_reducer;
+pub mod decline_invitation_reducer;
+pub mod decline_join_request_reducer;
+pub mod decline_rematch_reducer;
+pub mod emote_type;
+pub mod friend_request_reducer;
+pub mod friendship_table;
+pub mod friendship_type;
 pub mod game_duration_time_schedule_table;
 pub mod game_duration_time_schedule_type;
+pub mod game_end_expiry_table;
+pub mod game_end_expiry_type;
+pub mod game_end_reason_type;
+pub mod game_end_table;
+pub mod game_end_type;
+pub mod game_reaction_cleanup_table;
+pub mod game_reaction_cleanup_type;
+pub mod game_reaction_table;
+pub mod game_reaction_type;
+pub mod game_snapshot_expiry_table;
+pub mod game_snapshot_expiry_type;
+pub mod game_snapshot_table;
+pub mod game_snapshot_type;
 pub mod game_state_type;
 pub mod game_table;
 pub mod game_type;
 pub mod identity_connected_reducer;
 pub mod identity_disconnected_reducer;
+pub mod invitation_expiry_table;
+pub mod invitation_expiry_type;
+pub mod invitation_table;
+pub mod invitation_type;
+pub mod join_arena_reducer;
 pub mod join_game_reducer;
+pub mod join_request_table;
+pub mod join_request_type;
+pub mod join_tournament_reducer;
+pub mod keep_room_alive_reducer;
+pub mod leave_arena_reducer;
 pub mod leave_game_reducer;
 pub mod leave_room_reducer;
+pub mod live_game_summary_table;
+pub mod live_game_summary_type;
+pub mod lobby_room_cleanup_table;
+pub mod lobby_room_cleanup_type;
 pub mod lobby_room_table;
 pub mod lobby_room_type;
+pub mod lobby_settings_table;
+pub mod lobby_settings_type;
 pub mod mark_cell_reducer;
+pub mod motd_table;
+pub mod motd_type;
+pub mod play_vs_bot_reducer;
+pub mod player_rating_table;
+pub mod player_rating_type;
+pub mod player_report_table;
+pub mod player_report_type;
 pub mod player_type;
+pub mod presence_status_type;
+pub mod presence_table;
+pub mod presence_type;
+pub mod rate_limit_category_type;
+pub mod rate_limit_table;
+pub mod rate_limit_type;
+pub mod rematch_expiry_table;
+pub mod rematch_expiry_type;
+pub mod rematch_table;
+pub mod rematch_type;
+pub mod remove_friend_reducer;
+pub mod report_action_type;
+pub mod report_player_reducer;
+pub mod report_reason_type;
+pub mod request_rematch_reducer;
+pub mod resolve_report_reducer;
+pub mod scheduled_arena_end_reducer;
+pub mod scheduled_arena_pairing_reducer;
+pub mod scheduled_bot_turn_reducer;
+pub mod scheduled_game_end_expiry_reducer;
+pub mod scheduled_game_snapshot_expiry_reducer;
+pub mod scheduled_invitation_expiry_reducer;
+pub mod scheduled_lobby_room_cleanup_reducer;
+pub mod scheduled_reaction_cleanup_reducer;
+pub mod scheduled_rematch_expiry_reducer;
+pub mod scheduled_tournament_round_reducer;
 pub mod scheduled_turn_expiration_reducer;
-
+pub mod send_message_reducer;
+pub mod send_reaction_reducer;
+pub mod set_motd_reducer;
+pub mod side_preference_type;
+pub mod spectator_table;
+pub mod spectator_type;
+pub mod start_tournament_reducer;
+pub mod stop_watching_reducer;
+pub mod terminate_game_reducer;
+pub mod tournament_entrant_table;
+pub mod tournament_entrant_type;
+pub mod tournament_format_type;
+pub mod tournament_pairing_table;
+pub mod tournament_pairing_type;
+pub mod tournament_round_table;
+pub mod tournament_round_type;
+pub mod tournament_schedule_table;
+pub mod tournament_schedule_type;
+pub mod tournament_status_type;
+pub mod tournament_table;
+pub mod tournament_type;
+pub mod unban_player_reducer;
+pub mod unblock_player_reducer;
+pub mod watch_game_reducer;
+
+pub use accept_friend_reducer::{
+    accept_friend, set_flags_for_accept_friend, AcceptFriendCallbackId,
+};
+pub use accept_invitation_reducer::{
+    accept_invitation, set_flags_for_accept_invitation, AcceptInvitationCallbackId,
+};
+pub use accept_join_request_reducer::{
+    accept_join_request, set_flags_for_accept_join_request, AcceptJoinRequestCallbackId,
+};
+pub use accept_rematch_reducer::{
+    accept_rematch, set_flags_for_accept_rematch, AcceptRematchCallbackId,
+};
+pub use admin_close_room_reducer::{
+    admin_close_room, set_flags_for_admin_close_room, AdminCloseRoomCallbackId,
+};
+pub use admin_set_room_ttl_reducer::{
+    admin_set_room_ttl, set_flags_for_admin_set_room_ttl, AdminSetRoomTtlCallbackId,
+};
+pub use admin_table::*;
+pub use admin_type::Admin;
+pub use arena_end_schedule_table::*;
+pub use arena_end_schedule_type::ArenaEndSchedule;
+pub use arena_pairing_schedule_table::*;
+pub use arena_pairing_schedule_type::ArenaPairingSchedule;
+pub use arena_player_table::*;
+pub use arena_player_type::ArenaPlayer;
+pub use arena_table::*;
+pub use arena_type::Arena;
+pub use ban_player_reducer::{ban_player, set_flags_for_ban_player, BanPlayerCallbackId};
+pub use ban_table::*;
+pub use ban_type::Ban;
+pub use block_player_reducer::{block_player, set_flags_for_block_player, BlockPlayerCallbackId};
+pub use block_table::*;
+pub use block_type::Block;
+pub use bot_difficulty_type::BotDifficulty;
+pub use bot_turn_schedule_table::*;
+pub use bot_turn_schedule_type::BotTurnSchedule;
+pub use cancel_invitation_reducer::{
+    cancel_invitation, set_flags_for_cancel_invitation, CancelInvitationCallbackId,
+};
+pub use challenge_player_reducer::{
+    challenge_player, set_flags_for_challenge_player, ChallengePlayerCallbackId,
+};
+pub use chat_message_table::*;
+pub use chat_message_type::ChatMessage;
+pub use create_arena_reducer::{create_arena, set_flags_for_create_arena, CreateArenaCallbackId};
 pub use create_room_reducer::{create_room, set_flags_for_create_room, CreateRoomCallbackId};
+pub use create_tournament_reducer::{
+    create_tournament, set_flags_for_create_tournament, CreateTournamentCallbackId,
+};
+pub use decline_invitation_reducer::{
+    decline_invitation, set_flags_for_decline_invitation, DeclineInvitationCallbackId,
+};
+pub use decline_join_request_reducer::{
+    decline_join_request, set_flags_for_decline_join_request, DeclineJoinRequestCallbackId,
+};
+pub use decline_rematch_reducer::{
+    decline_rematch, set_flags_for_decline_rematch, DeclineRematchCallbackId,
+};
+pub use emote_type::Emote;
+pub use friend_request_reducer::{
+    friend_request, set_flags_for_friend_request, FriendRequestCallbackId,
+};
+pub use friendship_table::*;
+pub use friendship_type::Friendship;
 pub use game_duration_time_schedule_table::*;
 pub use game_duration_time_schedule_type::GameDurationTimeSchedule;
+pub use game_end_expiry_table::*;
+pub use game_end_expiry_type::GameEndExpiry;
+pub use game_end_reason_type::GameEndReason;
+pub use game_end_table::*;
+pub use game_end_type::GameEnd;
+pub use game_reaction_cleanup_table::*;
+pub use game_reaction_cleanup_type::GameReactionCleanup;
+pub use game_reaction_table::*;
+pub use game_reaction_type::GameReaction;
+pub use game_snapshot_expiry_table::*;
+pub use game_snapshot_expiry_type::GameSnapshotExpiry;
+pub use game_snapshot_table::*;
+pub use game_snapshot_type::GameSnapshot;
 pub use game_state_type::GameState;
 pub use game_table::*;
 pub use game_type::Game;
@@ -33,17 +234,139 @@ pub use identity_connected_reducer::{
 pub use identity_disconnected_reducer::{
     identity_disconnected, set_flags_for_identity_disconnected, IdentityDisconnectedCallbackId,
 };
+pub use invitation_expiry_table::*;
+pub use invitation_expiry_type::InvitationExpiry;
+pub use invitation_table::*;
+pub use invitation_type::Invitation;
+pub use join_arena_reducer::{join_arena, set_flags_for_join_arena, JoinArenaCallbackId};
 pub use join_game_reducer::{join_game, set_flags_for_join_game, JoinGameCallbackId};
+pub use join_request_table::*;
+pub use join_request_type::JoinRequest;
+pub use join_tournament_reducer::{
+    join_tournament, set_flags_for_join_tournament, JoinTournamentCallbackId,
+};
+pub use keep_room_alive_reducer::{
+    keep_room_alive, set_flags_for_keep_room_alive, KeepRoomAliveCallbackId,
+};
+pub use leave_arena_reducer::{leave_arena, set_flags_for_leave_arena, LeaveArenaCallbackId};
 pub use leave_game_reducer::{leave_game, set_flags_for_leave_game, LeaveGameCallbackId};
 pub use leave_room_reducer::{leave_room, set_flags_for_leave_room, LeaveRoomCallbackId};
+pub use live_game_summary_table::*;
+pub use live_game_summary_type::LiveGameSummary;
+pub use lobby_room_cleanup_table::*;
+pub use lobby_room_cleanup_type::LobbyRoomCleanup;
 pub use lobby_room_table::*;
 pub use lobby_room_type::LobbyRoom;
+pub use lobby_settings_table::*;
+pub use lobby_settings_type::LobbySettings;
 pub use mark_cell_reducer::{mark_cell, set_flags_for_mark_cell, MarkCellCallbackId};
+pub use motd_table::*;
+pub use motd_type::Motd;
+pub use play_vs_bot_reducer::{play_vs_bot, set_flags_for_play_vs_bot, PlayVsBotCallbackId};
+pub use player_rating_table::*;
+pub use player_rating_type::PlayerRating;
+pub use player_report_table::*;
+pub use player_report_type::PlayerReport;
 pub use player_type::Player;
+pub use presence_status_type::PresenceStatus;
+pub use presence_table::*;
+pub use presence_type::Presence;
+pub use rate_limit_category_type::RateLimitCategory;
+pub use rate_limit_table::*;
+pub use rate_limit_type::RateLimit;
+pub use rematch_expiry_table::*;
+pub use rematch_expiry_type::RematchExpiry;
+pub use rematch_table::*;
+pub use rematch_type::Rematch;
+pub use remove_friend_reducer::{
+    remove_friend, set_flags_for_remove_friend, RemoveFriendCallbackId,
+};
+pub use report_action_type::ReportAction;
+pub use report_player_reducer::{
+    report_player, set_flags_for_report_player, ReportPlayerCallbackId,
+};
+pub use report_reason_type::ReportReason;
+pub use request_rematch_reducer::{
+    request_rematch, set_flags_for_request_rematch, RequestRematchCallbackId,
+};
+pub use resolve_report_reducer::{
+    resolve_report, set_flags_for_resolve_report, ResolveReportCallbackId,
+};
+pub use scheduled_arena_end_reducer::{
+    scheduled_arena_end, set_flags_for_scheduled_arena_end, ScheduledArenaEndCallbackId,
+};
+pub use scheduled_arena_pairing_reducer::{
+    scheduled_arena_pairing, set_flags_for_scheduled_arena_pairing, ScheduledArenaPairingCallbackId,
+};
+pub use scheduled_bot_turn_reducer::{
+    scheduled_bot_turn, set_flags_for_scheduled_bot_turn, ScheduledBotTurnCallbackId,
+};
+pub use scheduled_game_end_expiry_reducer::{
+    scheduled_game_end_expiry, set_flags_for_scheduled_game_end_expiry,
+    ScheduledGameEndExpiryCallbackId,
+};
+pub use scheduled_game_snapshot_expiry_reducer::{
+    scheduled_game_snapshot_expiry, set_flags_for_scheduled_game_snapshot_expiry,
+    ScheduledGameSnapshotExpiryCallbackId,
+};
+pub use scheduled_invitation_expiry_reducer::{
+    scheduled_invitation_expiry, set_flags_for_scheduled_invitation_expiry,
+    ScheduledInvitationExpiryCallbackId,
+};
+pub use scheduled_lobby_room_cleanup_reducer::{
+    scheduled_lobby_room_cleanup, set_flags_for_scheduled_lobby_room_cleanup,
+    ScheduledLobbyRoomCleanupCallbackId,
+};
+pub use scheduled_reaction_cleanup_reducer::{
+    scheduled_reaction_cleanup, set_flags_for_scheduled_reaction_cleanup,
+    ScheduledReactionCleanupCallbackId,
+};
+pub use scheduled_rematch_expiry_reducer::{
+    scheduled_rematch_expiry, set_flags_for_scheduled_rematch_expiry,
+    ScheduledRematchExpiryCallbackId,
+};
+pub use scheduled_tournament_round_reducer::{
+    scheduled_tournament_round, set_flags_for_scheduled_tournament_round,
+    ScheduledTournamentRoundCallbackId,
+};
 pub use scheduled_turn_expiration_reducer::{
     scheduled_turn_expiration, set_flags_for_scheduled_turn_expiration,
     ScheduledTurnExpirationCallbackId,
 };
+pub use send_message_reducer::{send_message, set_flags_for_send_message, SendMessageCallbackId};
+pub use send_reaction_reducer::{
+    send_reaction, set_flags_for_send_reaction, SendReactionCallbackId,
+};
+pub use set_motd_reducer::{set_flags_for_set_motd, set_motd, SetMotdCallbackId};
+pub use side_preference_type::SidePreference;
+pub use spectator_table::*;
+pub use spectator_type::Spectator;
+pub use start_tournament_reducer::{
+    set_flags_for_start_tournament, start_tournament, StartTournamentCallbackId,
+};
+pub use stop_watching_reducer::{
+    set_flags_for_stop_watching, stop_watching, StopWatchingCallbackId,
+};
+pub use terminate_game_reducer::{
+    set_flags_for_terminate_game, terminate_game, TerminateGameCallbackId,
+};
+pub use tournament_entrant_table::*;
+pub use tournament_entrant_type::TournamentEntrant;
+pub use tournament_format_type::TournamentFormat;
+pub use tournament_pairing_table::*;
+pub use tournament_pairing_type::TournamentPairing;
+pub use tournament_round_table::*;
+pub use tournament_round_type::TournamentRound;
+pub use tournament_schedule_table::*;
+pub use tournament_schedule_type::TournamentSchedule;
+pub use tournament_status_type::TournamentStatus;
+pub use tournament_table::*;
+pub use tournament_type::Tournament;
+pub use unban_player_reducer::{set_flags_for_unban_player, unban_player, UnbanPlayerCallbackId};
+pub use unblock_player_reducer::{
+    set_flags_for_unblock_player, unblock_player, UnblockPlayerCallbackId,
+};
+pub use watch_game_reducer::{set_flags_for_watch_game, watch_game, WatchGameCallbackId};
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -53,14 +376,166 @@ pub use scheduled_turn_expiration_reducer::{
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
-    CreateRoom,
+    AcceptFriend {
+        friendship_id: u32,
+    },
+    AcceptInvitation {
+        invitation_id: u32,
+    },
+    AcceptJoinRequest {
+        request_id: u32,
+    },
+    AcceptRematch {
+        game_id: String,
+    },
+    AdminCloseRoom {
+        room_id: u32,
+    },
+    AdminSetRoomTtl {
+        room_ttl_secs: u32,
+    },
+    BanPlayer {
+        target: String,
+        minutes: u32,
+        reason: String,
+    },
+    BlockPlayer {
+        target: String,
+    },
+    CancelInvitation {
+        invitation_id: u32,
+    },
+    ChallengePlayer {
+        target: String,
+        side: SidePreference,
+        rated: bool,
+    },
+    CreateArena {
+        duration_minutes: u8,
+    },
+    CreateRoom {
+        owner_side: SidePreference,
+        rated: bool,
+    },
+    CreateTournament {
+        format: TournamentFormat,
+        max_entrants: u8,
+    },
+    DeclineInvitation {
+        invitation_id: u32,
+    },
+    DeclineJoinRequest {
+        request_id: u32,
+    },
+    DeclineRematch {
+        game_id: String,
+    },
+    FriendRequest {
+        target: String,
+    },
     IdentityConnected,
     IdentityDisconnected,
-    JoinGame { room_id: u32 },
-    LeaveGame { game_id: String },
+    JoinArena {
+        arena_id: u32,
+    },
+    JoinGame {
+        room_id: u32,
+    },
+    JoinTournament {
+        tournament_id: u32,
+    },
+    KeepRoomAlive,
+    LeaveArena {
+        arena_id: u32,
+    },
+    LeaveGame {
+        game_id: String,
+    },
     LeaveRoom,
-    MarkCell { game_id: String, cell: u16 },
-    ScheduledTurnExpiration { arg: GameDurationTimeSchedule },
+    MarkCell {
+        game_id: String,
+        cell: u16,
+    },
+    PlayVsBot {
+        difficulty: BotDifficulty,
+        side: SidePreference,
+    },
+    RemoveFriend {
+        friendship_id: u32,
+    },
+    ReportPlayer {
+        game_id: String,
+        reason: ReportReason,
+        text: String,
+    },
+    RequestRematch {
+        game_id: String,
+    },
+    ResolveReport {
+        report_id: u32,
+        action: ReportAction,
+        ban_minutes: u32,
+    },
+    ScheduledArenaEnd {
+        arg: ArenaEndSchedule,
+    },
+    ScheduledArenaPairing {
+        arg: ArenaPairingSchedule,
+    },
+    ScheduledBotTurn {
+        arg: BotTurnSchedule,
+    },
+    ScheduledGameEndExpiry {
+        arg: GameEndExpiry,
+    },
+    ScheduledGameSnapshotExpiry {
+        arg: GameSnapshotExpiry,
+    },
+    ScheduledInvitationExpiry {
+        arg: InvitationExpiry,
+    },
+    ScheduledLobbyRoomCleanup {
+        arg: LobbyRoomCleanup,
+    },
+    ScheduledReactionCleanup {
+        arg: GameReactionCleanup,
+    },
+    ScheduledRematchExpiry {
+        arg: RematchExpiry,
+    },
+    ScheduledTournamentRound {
+        arg: TournamentSchedule,
+    },
+    ScheduledTurnExpiration {
+        arg: GameDurationTimeSchedule,
+    },
+    SendMessage {
+        channel: String,
+        text: String,
+    },
+    SendReaction {
+        game_id: String,
+        emote: Emote,
+    },
+    SetMotd {
+        text: String,
+    },
+    StartTournament {
+        tournament_id: u32,
+    },
+    StopWatching,
+    TerminateGame {
+        game_id: String,
+    },
+    UnbanPlayer {
+        target: String,
+    },
+    UnblockPlayer {
+        block_id: u32,
+    },
+    WatchGame {
+        game_id: String,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -70,14 +545,58 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
-            Reducer::CreateRoom => "create_room",
+            Reducer::AcceptFriend { .. } => "accept_friend",
+            Reducer::AcceptInvitation { .. } => "accept_invitation",
+            Reducer::AcceptJoinRequest { .. } => "accept_join_request",
+            Reducer::AcceptRematch { .. } => "accept_rematch",
+            Reducer::AdminCloseRoom { .. } => "admin_close_room",
+            Reducer::AdminSetRoomTtl { .. } => "admin_set_room_ttl",
+            Reducer::BanPlayer { .. } => "ban_player",
+            Reducer::BlockPlayer { .. } => "block_player",
+            Reducer::CancelInvitation { .. } => "cancel_invitation",
+            Reducer::ChallengePlayer { .. } => "challenge_player",
+            Reducer::CreateArena { .. } => "create_arena",
+            Reducer::CreateRoom { .. } => "create_room",
+            Reducer::CreateTournament { .. } => "create_tournament",
+            Reducer::DeclineInvitation { .. } => "decline_invitation",
+            Reducer::DeclineJoinRequest { .. } => "decline_join_request",
+            Reducer::DeclineRematch { .. } => "decline_rematch",
+            Reducer::FriendRequest { .. } => "friend_request",
             Reducer::IdentityConnected => "identity_connected",
             Reducer::IdentityDisconnected => "identity_disconnected",
+            Reducer::JoinArena { .. } => "join_arena",
             Reducer::JoinGame { .. } => "join_game",
+            Reducer::JoinTournament { .. } => "join_tournament",
+            Reducer::KeepRoomAlive => "keep_room_alive",
+            Reducer::LeaveArena { .. } => "leave_arena",
             Reducer::LeaveGame { .. } => "leave_game",
             Reducer::LeaveRoom => "leave_room",
             Reducer::MarkCell { .. } => "mark_cell",
+            Reducer::PlayVsBot { .. } => "play_vs_bot",
+            Reducer::RemoveFriend { .. } => "remove_friend",
+            Reducer::ReportPlayer { .. } => "report_player",
+            Reducer::RequestRematch { .. } => "request_rematch",
+            Reducer::ResolveReport { .. } => "resolve_report",
+            Reducer::ScheduledArenaEnd { .. } => "scheduled_arena_end",
+            Reducer::ScheduledArenaPairing { .. } => "scheduled_arena_pairing",
+            Reducer::ScheduledBotTurn { .. } => "scheduled_bot_turn",
+            Reducer::ScheduledGameEndExpiry { .. } => "scheduled_game_end_expiry",
+            Reducer::ScheduledGameSnapshotExpiry { .. } => "scheduled_game_snapshot_expiry",
+            Reducer::ScheduledInvitationExpiry { .. } => "scheduled_invitation_expiry",
+            Reducer::ScheduledLobbyRoomCleanup { .. } => "scheduled_lobby_room_cleanup",
+            Reducer::ScheduledReactionCleanup { .. } => "scheduled_reaction_cleanup",
+            Reducer::ScheduledRematchExpiry { .. } => "scheduled_rematch_expiry",
+            Reducer::ScheduledTournamentRound { .. } => "scheduled_tournament_round",
             Reducer::ScheduledTurnExpiration { .. } => "scheduled_turn_expiration",
+            Reducer::SendMessage { .. } => "send_message",
+            Reducer::SendReaction { .. } => "send_reaction",
+            Reducer::SetMotd { .. } => "set_motd",
+            Reducer::StartTournament { .. } => "start_tournament",
+            Reducer::StopWatching => "stop_watching",
+            Reducer::TerminateGame { .. } => "terminate_game",
+            Reducer::UnbanPlayer { .. } => "unban_player",
+            Reducer::UnblockPlayer { .. } => "unblock_player",
+            Reducer::WatchGame { .. } => "watch_game",
         }
     }
 }
@@ -85,6 +604,59 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "accept_friend" => Ok(__sdk::parse_reducer_args::<
+                accept_friend_reducer::AcceptFriendArgs,
+            >("accept_friend", &value.args)?
+            .into()),
+            "accept_invitation" => Ok(__sdk::parse_reducer_args::<
+                accept_invitation_reducer::AcceptInvitationArgs,
+            >("accept_invitation", &value.args)?
+            .into()),
+            "accept_join_request" => Ok(__sdk::parse_reducer_args::<
+                accept_join_request_reducer::AcceptJoinRequestArgs,
+            >("accept_join_request", &value.args)?
+            .into()),
+            "accept_rematch" => Ok(__sdk::parse_reducer_args::<
+                accept_rematch_reducer::AcceptRematchArgs,
+            >("accept_rematch", &value.args)?
+            .into()),
+            "admin_close_room" => Ok(__sdk::parse_reducer_args::<
+                admin_close_room_reducer::AdminCloseRoomArgs,
+            >("admin_close_room", &value.args)?
+            .into()),
+            "admin_set_room_ttl" => Ok(__sdk::parse_reducer_args::<
+                admin_set_room_ttl_reducer::AdminSetRoomTtlArgs,
+            >("admin_set_room_ttl", &value.args)?
+            .into()),
+            "ban_player" => Ok(
+                __sdk::parse_reducer_args::<ban_player_reducer::BanPlayerArgs>(
+                    "ban_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "block_player" => Ok(
+                __sdk::parse_reducer_args::<block_player_reducer::BlockPlayerArgs>(
+                    "block_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "cancel_invitation" => Ok(__sdk::parse_reducer_args::<
+                cancel_invitation_reducer::CancelInvitationArgs,
+            >("cancel_invitation", &value.args)?
+            .into()),
+            "challenge_player" => Ok(__sdk::parse_reducer_args::<
+                challenge_player_reducer::ChallengePlayerArgs,
+            >("challenge_player", &value.args)?
+            .into()),
+            "create_arena" => Ok(
+                __sdk::parse_reducer_args::<create_arena_reducer::CreateArenaArgs>(
+                    "create_arena",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "create_room" => Ok(
                 __sdk::parse_reducer_args::<create_room_reducer::CreateRoomArgs>(
                     "create_room",
@@ -92,6 +664,26 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "create_tournament" => Ok(__sdk::parse_reducer_args::<
+                create_tournament_reducer::CreateTournamentArgs,
+            >("create_tournament", &value.args)?
+            .into()),
+            "decline_invitation" => Ok(__sdk::parse_reducer_args::<
+                decline_invitation_reducer::DeclineInvitationArgs,
+            >("decline_invitation", &value.args)?
+            .into()),
+            "decline_join_request" => Ok(__sdk::parse_reducer_args::<
+                decline_join_request_reducer::DeclineJoinRequestArgs,
+            >("decline_join_request", &value.args)?
+            .into()),
+            "decline_rematch" => Ok(__sdk::parse_reducer_args::<
+                decline_rematch_reducer::DeclineRematchArgs,
+            >("decline_rematch", &value.args)?
+            .into()),
+            "friend_request" => Ok(__sdk::parse_reducer_args::<
+                friend_request_reducer::FriendRequestArgs,
+            >("friend_request", &value.args)?
+            .into()),
             "identity_connected" => Ok(__sdk::parse_reducer_args::<
                 identity_connected_reducer::IdentityConnectedArgs,
             >("identity_connected", &value.args)?
@@ -100,6 +692,13 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 identity_disconnected_reducer::IdentityDisconnectedArgs,
             >("identity_disconnected", &value.args)?
             .into()),
+            "join_arena" => Ok(
+                __sdk::parse_reducer_args::<join_arena_reducer::JoinArenaArgs>(
+                    "join_arena",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "join_game" => Ok(
                 __sdk::parse_reducer_args::<join_game_reducer::JoinGameArgs>(
                     "join_game",
@@ -107,6 +706,21 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "join_tournament" => Ok(__sdk::parse_reducer_args::<
+                join_tournament_reducer::JoinTournamentArgs,
+            >("join_tournament", &value.args)?
+            .into()),
+            "keep_room_alive" => Ok(__sdk::parse_reducer_args::<
+                keep_room_alive_reducer::KeepRoomAliveArgs,
+            >("keep_room_alive", &value.args)?
+            .into()),
+            "leave_arena" => Ok(
+                __sdk::parse_reducer_args::<leave_arena_reducer::LeaveArenaArgs>(
+                    "leave_arena",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "leave_game" => Ok(
                 __sdk::parse_reducer_args::<leave_game_reducer::LeaveGameArgs>(
                     "leave_game",
@@ -128,12 +742,135 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "play_vs_bot" => Ok(
+                __sdk::parse_reducer_args::<play_vs_bot_reducer::PlayVsBotArgs>(
+                    "play_vs_bot",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "remove_friend" => Ok(__sdk::parse_reducer_args::<
+                remove_friend_reducer::RemoveFriendArgs,
+            >("remove_friend", &value.args)?
+            .into()),
+            "report_player" => Ok(__sdk::parse_reducer_args::<
+                report_player_reducer::ReportPlayerArgs,
+            >("report_player", &value.args)?
+            .into()),
+            "request_rematch" => Ok(__sdk::parse_reducer_args::<
+                request_rematch_reducer::RequestRematchArgs,
+            >("request_rematch", &value.args)?
+            .into()),
+            "resolve_report" => Ok(__sdk::parse_reducer_args::<
+                resolve_report_reducer::ResolveReportArgs,
+            >("resolve_report", &value.args)?
+            .into()),
+            "scheduled_arena_end" => Ok(__sdk::parse_reducer_args::<
+                scheduled_arena_end_reducer::ScheduledArenaEndArgs,
+            >("scheduled_arena_end", &value.args)?
+            .into()),
+            "scheduled_arena_pairing" => Ok(__sdk::parse_reducer_args::<
+                scheduled_arena_pairing_reducer::ScheduledArenaPairingArgs,
+            >("scheduled_arena_pairing", &value.args)?
+            .into()),
+            "scheduled_bot_turn" => Ok(__sdk::parse_reducer_args::<
+                scheduled_bot_turn_reducer::ScheduledBotTurnArgs,
+            >("scheduled_bot_turn", &value.args)?
+            .into()),
+            "scheduled_game_end_expiry" => {
+                Ok(__sdk::parse_reducer_args::<
+                    scheduled_game_end_expiry_reducer::ScheduledGameEndExpiryArgs,
+                >("scheduled_game_end_expiry", &value.args)?
+                .into())
+            }
+            "scheduled_game_snapshot_expiry" => {
+                Ok(__sdk::parse_reducer_args::<
+                    scheduled_game_snapshot_expiry_reducer::ScheduledGameSnapshotExpiryArgs,
+                >("scheduled_game_snapshot_expiry", &value.args)?
+                .into())
+            }
+            "scheduled_invitation_expiry" => {
+                Ok(__sdk::parse_reducer_args::<
+                    scheduled_invitation_expiry_reducer::ScheduledInvitationExpiryArgs,
+                >("scheduled_invitation_expiry", &value.args)?
+                .into())
+            }
+            "scheduled_lobby_room_cleanup" => {
+                Ok(__sdk::parse_reducer_args::<
+                    scheduled_lobby_room_cleanup_reducer::ScheduledLobbyRoomCleanupArgs,
+                >("scheduled_lobby_room_cleanup", &value.args)?
+                .into())
+            }
+            "scheduled_reaction_cleanup" => {
+                Ok(__sdk::parse_reducer_args::<
+                    scheduled_reaction_cleanup_reducer::ScheduledReactionCleanupArgs,
+                >("scheduled_reaction_cleanup", &value.args)?
+                .into())
+            }
+            "scheduled_rematch_expiry" => {
+                Ok(__sdk::parse_reducer_args::<
+                    scheduled_rematch_expiry_reducer::ScheduledRematchExpiryArgs,
+                >("scheduled_rematch_expiry", &value.args)?
+                .into())
+            }
+            "scheduled_tournament_round" => {
+                Ok(__sdk::parse_reducer_args::<
+                    scheduled_tournament_round_reducer::ScheduledTournamentRoundArgs,
+                >("scheduled_tournament_round", &value.args)?
+                .into())
+            }
             "scheduled_turn_expiration" => {
                 Ok(__sdk::parse_reducer_args::<
                     scheduled_turn_expiration_reducer::ScheduledTurnExpirationArgs,
                 >("scheduled_turn_expiration", &value.args)?
                 .into())
             }
+            "send_message" => Ok(
+                __sdk::parse_reducer_args::<send_message_reducer::SendMessageArgs>(
+                    "send_message",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "send_reaction" => Ok(__sdk::parse_reducer_args::<
+                send_reaction_reducer::SendReactionArgs,
+            >("send_reaction", &value.args)?
+            .into()),
+            "set_motd" => Ok(__sdk::parse_reducer_args::<set_motd_reducer::SetMotdArgs>(
+                "set_motd",
+                &value.args,
+            )?
+            .into()),
+            "start_tournament" => Ok(__sdk::parse_reducer_args::<
+                start_tournament_reducer::StartTournamentArgs,
+            >("start_tournament", &value.args)?
+            .into()),
+            "stop_watching" => Ok(__sdk::parse_reducer_args::<
+                stop_watching_reducer::StopWatchingArgs,
+            >("stop_watching", &value.args)?
+            .into()),
+            "terminate_game" => Ok(__sdk::parse_reducer_args::<
+                terminate_game_reducer::TerminateGameArgs,
+            >("terminate_game", &value.args)?
+            .into()),
+            "unban_player" => Ok(
+                __sdk::parse_reducer_args::<unban_player_reducer::UnbanPlayerArgs>(
+                    "unban_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "unblock_player" => Ok(__sdk::parse_reducer_args::<
+                unblock_player_reducer::UnblockPlayerArgs,
+            >("unblock_player", &value.args)?
+            .into()),
+            "watch_game" => Ok(
+                __sdk::parse_reducer_args::<watch_game_reducer::WatchGameArgs>(
+                    "watch_game",
+                    &value.args,
+                )?
+                .into(),
+            ),
             unknown => {
                 Err(
                     __sdk::InternalError::unknown_name("reducer", unknown, "ReducerCallInfo")
@@ -148,9 +885,44 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    admin: __sdk::TableUpdate<Admin>,
+    arena: __sdk::TableUpdate<Arena>,
+    arena_end_schedule: __sdk::TableUpdate<ArenaEndSchedule>,
+    arena_pairing_schedule: __sdk::TableUpdate<ArenaPairingSchedule>,
+    arena_player: __sdk::TableUpdate<ArenaPlayer>,
+    ban: __sdk::TableUpdate<Ban>,
+    block: __sdk::TableUpdate<Block>,
+    bot_turn_schedule: __sdk::TableUpdate<BotTurnSchedule>,
+    chat_message: __sdk::TableUpdate<ChatMessage>,
+    friendship: __sdk::TableUpdate<Friendship>,
     game: __sdk::TableUpdate<Game>,
     game_duration_time_schedule: __sdk::TableUpdate<GameDurationTimeSchedule>,
+    game_end: __sdk::TableUpdate<GameEnd>,
+    game_end_expiry: __sdk::TableUpdate<GameEndExpiry>,
+    game_reaction: __sdk::TableUpdate<GameReaction>,
+    game_reaction_cleanup: __sdk::TableUpdate<GameReactionCleanup>,
+    game_snapshot: __sdk::TableUpdate<GameSnapshot>,
+    game_snapshot_expiry: __sdk::TableUpdate<GameSnapshotExpiry>,
+    invitation: __sdk::TableUpdate<Invitation>,
+    invitation_expiry: __sdk::TableUpdate<InvitationExpiry>,
+    join_request: __sdk::TableUpdate<JoinRequest>,
+    live_game_summary: __sdk::TableUpdate<LiveGameSummary>,
     lobby_room: __sdk::TableUpdate<LobbyRoom>,
+    lobby_room_cleanup: __sdk::TableUpdate<LobbyRoomCleanup>,
+    lobby_settings: __sdk::TableUpdate<LobbySettings>,
+    motd: __sdk::TableUpdate<Motd>,
+    player_rating: __sdk::TableUpdate<PlayerRating>,
+    player_report: __sdk::TableUpdate<PlayerReport>,
+    presence: __sdk::TableUpdate<Presence>,
+    rate_limit: __sdk::TableUpdate<RateLimit>,
+    rematch: __sdk::TableUpdate<Rematch>,
+    rematch_expiry: __sdk::TableUpdate<RematchExpiry>,
+    spectator: __sdk::TableUpdate<Spectator>,
+    tournament: __sdk::TableUpdate<Tournament>,
+    tournament_entrant: __sdk::TableUpdate<TournamentEntrant>,
+    tournament_pairing: __sdk::TableUpdate<TournamentPairing>,
+    tournament_round: __sdk::TableUpdate<TournamentRound>,
+    tournament_schedule: __sdk::TableUpdate<TournamentSchedule>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -159,14 +931,122 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "admin" => db_update.admin = admin_table::parse_table_update(table_update)?,
+                "arena" => db_update.arena = arena_table::parse_table_update(table_update)?,
+                "arena_end_schedule" => {
+                    db_update.arena_end_schedule =
+                        arena_end_schedule_table::parse_table_update(table_update)?
+                }
+                "arena_pairing_schedule" => {
+                    db_update.arena_pairing_schedule =
+                        arena_pairing_schedule_table::parse_table_update(table_update)?
+                }
+                "arena_player" => {
+                    db_update.arena_player = arena_player_table::parse_table_update(table_update)?
+                }
+                "ban" => db_update.ban = ban_table::parse_table_update(table_update)?,
+                "block" => db_update.block = block_table::parse_table_update(table_update)?,
+                "bot_turn_schedule" => {
+                    db_update.bot_turn_schedule =
+                        bot_turn_schedule_table::parse_table_update(table_update)?
+                }
+                "chat_message" => {
+                    db_update.chat_message = chat_message_table::parse_table_update(table_update)?
+                }
+                "friendship" => {
+                    db_update.friendship = friendship_table::parse_table_update(table_update)?
+                }
                 "game" => db_update.game = game_table::parse_table_update(table_update)?,
                 "game_duration_time_schedule" => {
                     db_update.game_duration_time_schedule =
                         game_duration_time_schedule_table::parse_table_update(table_update)?
                 }
+                "game_end" => {
+                    db_update.game_end = game_end_table::parse_table_update(table_update)?
+                }
+                "game_end_expiry" => {
+                    db_update.game_end_expiry =
+                        game_end_expiry_table::parse_table_update(table_update)?
+                }
+                "game_reaction" => {
+                    db_update.game_reaction = game_reaction_table::parse_table_update(table_update)?
+                }
+                "game_reaction_cleanup" => {
+                    db_update.game_reaction_cleanup =
+                        game_reaction_cleanup_table::parse_table_update(table_update)?
+                }
+                "game_snapshot" => {
+                    db_update.game_snapshot = game_snapshot_table::parse_table_update(table_update)?
+                }
+                "game_snapshot_expiry" => {
+                    db_update.game_snapshot_expiry =
+                        game_snapshot_expiry_table::parse_table_update(table_update)?
+                }
+                "invitation" => {
+                    db_update.invitation = invitation_table::parse_table_update(table_update)?
+                }
+                "invitation_expiry" => {
+                    db_update.invitation_expiry =
+                        invitation_expiry_table::parse_table_update(table_update)?
+                }
+                "join_request" => {
+                    db_update.join_request = join_request_table::parse_table_update(table_update)?
+                }
+                "live_game_summary" => {
+                    db_update.live_game_summary =
+                        live_game_summary_table::parse_table_update(table_update)?
+                }
                 "lobby_room" => {
                     db_update.lobby_room = lobby_room_table::parse_table_update(table_update)?
                 }
+                "lobby_room_cleanup" => {
+                    db_update.lobby_room_cleanup =
+                        lobby_room_cleanup_table::parse_table_update(table_update)?
+                }
+                "lobby_settings" => {
+                    db_update.lobby_settings =
+                        lobby_settings_table::parse_table_update(table_update)?
+                }
+                "motd" => db_update.motd = motd_table::parse_table_update(table_update)?,
+                "player_rating" => {
+                    db_update.player_rating = player_rating_table::parse_table_update(table_update)?
+                }
+                "player_report" => {
+                    db_update.player_report = player_report_table::parse_table_update(table_update)?
+                }
+                "presence" => {
+                    db_update.presence = presence_table::parse_table_update(table_update)?
+                }
+                "rate_limit" => {
+                    db_update.rate_limit = rate_limit_table::parse_table_update(table_update)?
+                }
+                "rematch" => db_update.rematch = rematch_table::parse_table_update(table_update)?,
+                "rematch_expiry" => {
+                    db_update.rematch_expiry =
+                        rematch_expiry_table::parse_table_update(table_update)?
+                }
+                "spectator" => {
+                    db_update.spectator = spectator_table::parse_table_update(table_update)?
+                }
+                "tournament" => {
+                    db_update.tournament = tournament_table::parse_table_update(table_update)?
+                }
+                "tournament_entrant" => {
+                    db_update.tournament_entrant =
+                        tournament_entrant_table::parse_table_update(table_update)?
+                }
+                "tournament_pairing" => {
+                    db_update.tournament_pairing =
+                        tournament_pairing_table::parse_table_update(table_update)?
+                }
+                "tournament_round" => {
+                    db_update.tournament_round =
+                        tournament_round_table::parse_table_update(table_update)?
+                }
+                "tournament_schedule" => {
+                    db_update.tournament_schedule =
+                        tournament_schedule_table::parse_table_update(table_update)?
+                }
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -193,6 +1073,39 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.admin = cache
+            .apply_diff_to_table::<Admin>("admin", &self.admin)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.arena = cache
+            .apply_diff_to_table::<Arena>("arena", &self.arena)
+            .with_updates_by_pk(|row| &row.id);
+        diff.arena_end_schedule = cache
+            .apply_diff_to_table::<ArenaEndSchedule>("arena_end_schedule", &self.arena_end_schedule)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.arena_pairing_schedule = cache
+            .apply_diff_to_table::<ArenaPairingSchedule>(
+                "arena_pairing_schedule",
+                &self.arena_pairing_schedule,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.arena_player = cache
+            .apply_diff_to_table::<ArenaPlayer>("arena_player", &self.arena_player)
+            .with_updates_by_pk(|row| &row.id);
+        diff.ban = cache
+            .apply_diff_to_table::<Ban>("ban", &self.ban)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.block = cache
+            .apply_diff_to_table::<Block>("block", &self.block)
+            .with_updates_by_pk(|row| &row.id);
+        diff.bot_turn_schedule = cache
+            .apply_diff_to_table::<BotTurnSchedule>("bot_turn_schedule", &self.bot_turn_schedule)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.chat_message = cache
+            .apply_diff_to_table::<ChatMessage>("chat_message", &self.chat_message)
+            .with_updates_by_pk(|row| &row.id);
+        diff.friendship = cache
+            .apply_diff_to_table::<Friendship>("friendship", &self.friendship)
+            .with_updates_by_pk(|row| &row.id);
         diff.game = cache
             .apply_diff_to_table::<Game>("game", &self.game)
             .with_updates_by_pk(|row| &row.id);
@@ -202,9 +1115,99 @@ impl __sdk::DbUpdate for DbUpdate {
                 &self.game_duration_time_schedule,
             )
             .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.game_end = cache
+            .apply_diff_to_table::<GameEnd>("game_end", &self.game_end)
+            .with_updates_by_pk(|row| &row.game_id);
+        diff.game_end_expiry = cache
+            .apply_diff_to_table::<GameEndExpiry>("game_end_expiry", &self.game_end_expiry)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.game_reaction = cache
+            .apply_diff_to_table::<GameReaction>("game_reaction", &self.game_reaction)
+            .with_updates_by_pk(|row| &row.id);
+        diff.game_reaction_cleanup = cache
+            .apply_diff_to_table::<GameReactionCleanup>(
+                "game_reaction_cleanup",
+                &self.game_reaction_cleanup,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.game_snapshot = cache
+            .apply_diff_to_table::<GameSnapshot>("game_snapshot", &self.game_snapshot)
+            .with_updates_by_pk(|row| &row.game_id);
+        diff.game_snapshot_expiry = cache
+            .apply_diff_to_table::<GameSnapshotExpiry>(
+                "game_snapshot_expiry",
+                &self.game_snapshot_expiry,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.invitation = cache
+            .apply_diff_to_table::<Invitation>("invitation", &self.invitation)
+            .with_updates_by_pk(|row| &row.id);
+        diff.invitation_expiry = cache
+            .apply_diff_to_table::<InvitationExpiry>("invitation_expiry", &self.invitation_expiry)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.join_request = cache
+            .apply_diff_to_table::<JoinRequest>("join_request", &self.join_request)
+            .with_updates_by_pk(|row| &row.id);
+        diff.live_game_summary = cache
+            .apply_diff_to_table::<LiveGameSummary>("live_game_summary", &self.live_game_summary)
+            .with_updates_by_pk(|row| &row.game_id);
         diff.lobby_room = cache
             .apply_diff_to_table::<LobbyRoom>("lobby_room", &self.lobby_room)
             .with_updates_by_pk(|row| &row.id);
+        diff.lobby_room_cleanup = cache
+            .apply_diff_to_table::<LobbyRoomCleanup>("lobby_room_cleanup", &self.lobby_room_cleanup)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.lobby_settings = cache
+            .apply_diff_to_table::<LobbySettings>("lobby_settings", &self.lobby_settings)
+            .with_updates_by_pk(|row| &row.id);
+        diff.motd = cache
+            .apply_diff_to_table::<Motd>("motd", &self.motd)
+            .with_updates_by_pk(|row| &row.id);
+        diff.player_rating = cache
+            .apply_diff_to_table::<PlayerRating>("player_rating", &self.player_rating)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.player_report = cache
+            .apply_diff_to_table::<PlayerReport>("player_report", &self.player_report)
+            .with_updates_by_pk(|row| &row.id);
+        diff.presence = cache
+            .apply_diff_to_table::<Presence>("presence", &self.presence)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.rate_limit = cache
+            .apply_diff_to_table::<RateLimit>("rate_limit", &self.rate_limit)
+            .with_updates_by_pk(|row| &row.id);
+        diff.rematch = cache
+            .apply_diff_to_table::<Rematch>("rematch", &self.rematch)
+            .with_updates_by_pk(|row| &row.game_id);
+        diff.rematch_expiry = cache
+            .apply_diff_to_table::<RematchExpiry>("rematch_expiry", &self.rematch_expiry)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.spectator = cache
+            .apply_diff_to_table::<Spectator>("spectator", &self.spectator)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.tournament = cache
+            .apply_diff_to_table::<Tournament>("tournament", &self.tournament)
+            .with_updates_by_pk(|row| &row.id);
+        diff.tournament_entrant = cache
+            .apply_diff_to_table::<TournamentEntrant>(
+                "tournament_entrant",
+                &self.tournament_entrant,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.tournament_pairing = cache
+            .apply_diff_to_table::<TournamentPairing>(
+                "tournament_pairing",
+                &self.tournament_pairing,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.tournament_round = cache
+            .apply_diff_to_table::<TournamentRound>("tournament_round", &self.tournament_round)
+            .with_updates_by_pk(|row| &row.id);
+        diff.tournament_schedule = cache
+            .apply_diff_to_table::<TournamentSchedule>(
+                "tournament_schedule",
+                &self.tournament_schedule,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
 
         diff
     }
@@ -214,9 +1217,44 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    admin: __sdk::TableAppliedDiff<'r, Admin>,
+    arena: __sdk::TableAppliedDiff<'r, Arena>,
+    arena_end_schedule: __sdk::TableAppliedDiff<'r, ArenaEndSchedule>,
+    arena_pairing_schedule: __sdk::TableAppliedDiff<'r, ArenaPairingSchedule>,
+    arena_player: __sdk::TableAppliedDiff<'r, ArenaPlayer>,
+    ban: __sdk::TableAppliedDiff<'r, Ban>,
+    block: __sdk::TableAppliedDiff<'r, Block>,
+    bot_turn_schedule: __sdk::TableAppliedDiff<'r, BotTurnSchedule>,
+    chat_message: __sdk::TableAppliedDiff<'r, ChatMessage>,
+    friendship: __sdk::TableAppliedDiff<'r, Friendship>,
     game: __sdk::TableAppliedDiff<'r, Game>,
     game_duration_time_schedule: __sdk::TableAppliedDiff<'r, GameDurationTimeSchedule>,
+    game_end: __sdk::TableAppliedDiff<'r, GameEnd>,
+    game_end_expiry: __sdk::TableAppliedDiff<'r, GameEndExpiry>,
+    game_reaction: __sdk::TableAppliedDiff<'r, GameReaction>,
+    game_reaction_cleanup: __sdk::TableAppliedDiff<'r, GameReactionCleanup>,
+    game_snapshot: __sdk::TableAppliedDiff<'r, GameSnapshot>,
+    game_snapshot_expiry: __sdk::TableAppliedDiff<'r, GameSnapshotExpiry>,
+    invitation: __sdk::TableAppliedDiff<'r, Invitation>,
+    invitation_expiry: __sdk::TableAppliedDiff<'r, InvitationExpiry>,
+    join_request: __sdk::TableAppliedDiff<'r, JoinRequest>,
+    live_game_summary: __sdk::TableAppliedDiff<'r, LiveGameSummary>,
     lobby_room: __sdk::TableAppliedDiff<'r, LobbyRoom>,
+    lobby_room_cleanup: __sdk::TableAppliedDiff<'r, LobbyRoomCleanup>,
+    lobby_settings: __sdk::TableAppliedDiff<'r, LobbySettings>,
+    motd: __sdk::TableAppliedDiff<'r, Motd>,
+    player_rating: __sdk::TableAppliedDiff<'r, PlayerRating>,
+    player_report: __sdk::TableAppliedDiff<'r, PlayerReport>,
+    presence: __sdk::TableAppliedDiff<'r, Presence>,
+    rate_limit: __sdk::TableAppliedDiff<'r, RateLimit>,
+    rematch: __sdk::TableAppliedDiff<'r, Rematch>,
+    rematch_expiry: __sdk::TableAppliedDiff<'r, RematchExpiry>,
+    spectator: __sdk::TableAppliedDiff<'r, Spectator>,
+    tournament: __sdk::TableAppliedDiff<'r, Tournament>,
+    tournament_entrant: __sdk::TableAppliedDiff<'r, TournamentEntrant>,
+    tournament_pairing: __sdk::TableAppliedDiff<'r, TournamentPairing>,
+    tournament_round: __sdk::TableAppliedDiff<'r, TournamentRound>,
+    tournament_schedule: __sdk::TableAppliedDiff<'r, TournamentSchedule>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -229,13 +1267,136 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<Admin>("admin", &self.admin, event);
+        callbacks.invoke_table_row_callbacks::<Arena>("arena", &self.arena, event);
+        callbacks.invoke_table_row_callbacks::<ArenaEndSchedule>(
+            "arena_end_schedule",
+            &self.arena_end_schedule,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ArenaPairingSchedule>(
+            "arena_pairing_schedule",
+            &self.arena_pairing_schedule,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ArenaPlayer>(
+            "arena_player",
+            &self.arena_player,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Ban>("ban", &self.ban, event);
+        callbacks.invoke_table_row_callbacks::<Block>("block", &self.block, event);
+        callbacks.invoke_table_row_callbacks::<BotTurnSchedule>(
+            "bot_turn_schedule",
+            &self.bot_turn_schedule,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ChatMessage>(
+            "chat_message",
+            &self.chat_message,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Friendship>("friendship", &self.friendship, event);
         callbacks.invoke_table_row_callbacks::<Game>("game", &self.game, event);
         callbacks.invoke_table_row_callbacks::<GameDurationTimeSchedule>(
             "game_duration_time_schedule",
             &self.game_duration_time_schedule,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<GameEnd>("game_end", &self.game_end, event);
+        callbacks.invoke_table_row_callbacks::<GameEndExpiry>(
+            "game_end_expiry",
+            &self.game_end_expiry,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<GameReaction>(
+            "game_reaction",
+            &self.game_reaction,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<GameReactionCleanup>(
+            "game_reaction_cleanup",
+            &self.game_reaction_cleanup,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<GameSnapshot>(
+            "game_snapshot",
+            &self.game_snapshot,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<GameSnapshotExpiry>(
+            "game_snapshot_expiry",
+            &self.game_snapshot_expiry,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Invitation>("invitation", &self.invitation, event);
+        callbacks.invoke_table_row_callbacks::<InvitationExpiry>(
+            "invitation_expiry",
+            &self.invitation_expiry,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<JoinRequest>(
+            "join_request",
+            &self.join_request,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LiveGameSummary>(
+            "live_game_summary",
+            &self.live_game_summary,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<LobbyRoom>("lobby_room", &self.lobby_room, event);
+        callbacks.invoke_table_row_callbacks::<LobbyRoomCleanup>(
+            "lobby_room_cleanup",
+            &self.lobby_room_cleanup,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LobbySettings>(
+            "lobby_settings",
+            &self.lobby_settings,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Motd>("motd", &self.motd, event);
+        callbacks.invoke_table_row_callbacks::<PlayerRating>(
+            "player_rating",
+            &self.player_rating,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PlayerReport>(
+            "player_report",
+            &self.player_report,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Presence>("presence", &self.presence, event);
+        callbacks.invoke_table_row_callbacks::<RateLimit>("rate_limit", &self.rate_limit, event);
+        callbacks.invoke_table_row_callbacks::<Rematch>("rematch", &self.rematch, event);
+        callbacks.invoke_table_row_callbacks::<RematchExpiry>(
+            "rematch_expiry",
+            &self.rematch_expiry,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Spectator>("spectator", &self.spectator, event);
+        callbacks.invoke_table_row_callbacks::<Tournament>("tournament", &self.tournament, event);
+        callbacks.invoke_table_row_callbacks::<TournamentEntrant>(
+            "tournament_entrant",
+            &self.tournament_entrant,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<TournamentPairing>(
+            "tournament_pairing",
+            &self.tournament_pairing,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<TournamentRound>(
+            "tournament_round",
+            &self.tournament_round,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<TournamentSchedule>(
+            "tournament_schedule",
+            &self.tournament_schedule,
+            event,
+        );
     }
 }
 
@@ -395,8 +1556,10 @@ impl DbConnection {
     pub fn advance_one_message_blocking(&self) -> __sdk::Result<()> {
         #[cfg(target_arch = "wasm32")]
         {
-            panic!("`DbConnection::advance_one_message_blocking` is not supported on WebAssembly (wasm32); \
-            prefer using `advance_one_message` or `advance_one_message_async` instead");
+            panic!(
+                "`DbConnection::advance_one_message_blocking` is not supported on WebAssembly (wasm32); \
+            prefer using `advance_one_message` or `advance_one_message_async` instead"
+            );
         }
         #[cfg(not(target_arch = "wasm32"))]
         {
@@ -854,8 +2017,43 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        admin_table::register_table(client_cache);
+        arena_table::register_table(client_cache);
+        arena_end_schedule_table::register_table(client_cache);
+        arena_pairing_schedule_table::register_table(client_cache);
+        arena_player_table::register_table(client_cache);
+        ban_table::register_table(client_cache);
+        block_table::register_table(client_cache);
+        bot_turn_schedule_table::register_table(client_cache);
+        chat_message_table::register_table(client_cache);
+        friendship_table::register_table(client_cache);
         game_table::register_table(client_cache);
         game_duration_time_schedule_table::register_table(client_cache);
+        game_end_table::register_table(client_cache);
+        game_end_expiry_table::register_table(client_cache);
+        game_reaction_table::register_table(client_cache);
+        game_reaction_cleanup_table::register_table(client_cache);
+        game_snapshot_table::register_table(client_cache);
+        game_snapshot_expiry_table::register_table(client_cache);
+        invitation_table::register_table(client_cache);
+        invitation_expiry_table::register_table(client_cache);
+        join_request_table::register_table(client_cache);
+        live_game_summary_table::register_table(client_cache);
         lobby_room_table::register_table(client_cache);
+        lobby_room_cleanup_table::register_table(client_cache);
+        lobby_settings_table::register_table(client_cache);
+        motd_table::register_table(client_cache);
+        player_rating_table::register_table(client_cache);
+        player_report_table::register_table(client_cache);
+        presence_table::register_table(client_cache);
+        rate_limit_table::register_table(client_cache);
+        rematch_table::register_table(client_cache);
+        rematch_expiry_table::register_table(client_cache);
+        spectator_table::register_table(client_cache);
+        tournament_table::register_table(client_cache);
+        tournament_entrant_table::register_table(client_cache);
+        tournament_pairing_table::register_table(client_cache);
+        tournament_round_table::register_table(client_cache);
+        tournament_schedule_table::register_table(client_cache);
     }
 }
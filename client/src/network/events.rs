@@ -2,6 +2,7 @@ use std::marker::PhantomData;
 
 use bevy::prelude::{Deref, DerefMut, Event};
 use spacetimedb_sdk::{Error as StdbError, Identity};
+use tictactoe_rules::ErrorCode;
 
 use super::NetworkAuth;
 
@@ -19,6 +20,21 @@ pub struct OnUpdate<Row> {
     pub new: Row,
 }
 
+/// Outcome of a reducer called by this client, `result` holds the server's error on failure
+pub struct OnReducer<Call> {
+    pub call: Call,
+    pub result: Result<(), String>,
+}
+
+/// A reducer called by this client was turned down, whichever it was
+pub struct ReducerFailed {
+    pub reducer: &'static str,
+    /// `None` when the server didn't say, e.g. running out of energy
+    pub code: Option<ErrorCode>,
+    /// What to show the player, without the code
+    pub message: String,
+}
+
 #[derive(Deref, DerefMut)]
 pub struct OnConnect(pub NetworkAuth);
 
@@ -80,6 +80,64 @@ macro_rules! stdb_lifecycle_events {
     }};
 }
 
+/// Declares a `<Reducer>Call` struct with the arguments of every listed reducer, along with:
+/// - `register_reducer_events`, declaring their events without subscribing to the callbacks
+/// - `register_reducer_callbacks`, forwarding each `on_<reducer>` callback as
+///   `Stdb<OnReducer<<Reducer>Call>>`, and its failures as `Stdb<ReducerFailed>` too
+macro_rules! stdb_reducers {
+    ($( $reducer:ident ( $($arg:ident : $arg_ty:ty),* ) ),* $(,)?) => {
+        paste::paste! {
+            $(
+                // Only some of the calls get their arguments looked at
+                #[allow(dead_code)]
+                #[derive(Clone, Debug)]
+                pub struct [<$reducer:camel Call>] {
+                    $(pub $arg: $arg_ty,)*
+                }
+            )*
+
+            fn register_reducer_events(app: &mut App) {
+                stdb_register_event!(app, ReducerFailed $(, OnReducer<[<$reducer:camel Call>]>)*);
+            }
+
+            fn register_reducer_callbacks(ctx: &mut Context, conn: &mut DbConnection) {
+                let (failed_tx, failed_rx) = crossbeam_channel::unbounded::<ReducerFailed>();
+                stdb_register_event!(ctx, ReducerFailed, failed_rx);
+                $(
+                    let (tx, rx) =
+                        crossbeam_channel::unbounded::<OnReducer<[<$reducer:camel Call>]>>();
+                    stdb_register_event!(ctx, OnReducer<[<$reducer:camel Call>]>, rx);
+                    let reducer_failed_tx = failed_tx.clone();
+                    conn.reducers.[<on_ $reducer>](move |event_ctx, $($arg),*| {
+                        let result = match &event_ctx.event.status {
+                            spacetimedb_sdk::Status::Committed => Ok(()),
+                            spacetimedb_sdk::Status::Failed(message) => Err(message.to_string()),
+                            spacetimedb_sdk::Status::OutOfEnergy => {
+                                Err("Out of energy".to_string())
+                            }
+                        };
+                        if let Err(error) = &result {
+                            let (code, message) = tictactoe_rules::ErrorCode::split(error);
+                            reducer_failed_tx
+                                .send(ReducerFailed {
+                                    reducer: stringify!($reducer),
+                                    code,
+                                    message: message.to_string(),
+                                })
+                                .unwrap();
+                        }
+                        tx.send(OnReducer {
+                            call: [<$reducer:camel Call>] { $($arg: $arg.clone()),* },
+                            result,
+                        })
+                        .unwrap();
+                    });
+                )*
+            }
+        }
+    };
+}
+
 // Register the Callbacks wrapped by a Stdb
 //
 // On the web we poll the connection so we can't subscribe before the it's polled,
@@ -13,12 +13,36 @@ use bevy::tasks::AsyncComputeTaskPool;
 use spacetimedb_sdk::{DbContext, Table, TableWithPrimaryKey};
 
 use bindings::*;
-use events::{OnInsert, *};
+use events::*;
 
 pub use bindings::{
-    LobbyRoomTableAccess, Player, create_room as CreateRoom, join_game as JoinGame,
+    Arena, ArenaPlayer, ArenaPlayerTableAccess, ArenaTableAccess, Block, BlockTableAccess,
+    BotDifficulty, ChatMessage, ChatMessageTableAccess, Emote, Friendship, FriendshipTableAccess,
+    Game, GameEndReason, GameEndTableAccess, GameReaction, GameState, GameTableAccess, Invitation,
+    InvitationTableAccess, JoinRequest, JoinRequestTableAccess, LiveGameSummary,
+    LiveGameSummaryTableAccess, LobbyRoom, LobbyRoomTableAccess, Motd, MotdTableAccess, Player,
+    PlayerRatingTableAccess, Presence, PresenceStatus, PresenceTableAccess, Rematch,
+    RematchTableAccess, ReportReason, SidePreference, Tournament, TournamentEntrant,
+    TournamentEntrantTableAccess, TournamentFormat, TournamentPairing,
+    TournamentPairingTableAccess, TournamentStatus, TournamentTableAccess,
+    accept_friend as AcceptFriend, accept_invitation as AcceptInvitation,
+    accept_join_request as AcceptJoinRequest, accept_rematch as AcceptRematch,
+    block_player as BlockPlayer, cancel_invitation as CancelInvitation,
+    challenge_player as ChallengePlayer, create_arena as CreateArena, create_room as CreateRoom,
+    create_tournament as CreateTournament, decline_invitation as DeclineInvitation,
+    decline_join_request as DeclineJoinRequest, decline_rematch as DeclineRematch,
+    friend_request as FriendRequest, join_arena as JoinArena, join_game as JoinGame,
+    join_tournament as JoinTournament, keep_room_alive as KeepRoomAlive, leave_arena as LeaveArena,
     leave_game as LeaveGame, leave_room as LeaveRoom, mark_cell as MarkCell,
+    play_vs_bot as PlayVsBot, remove_friend as RemoveFriend, report_player as ReportPlayer,
+    request_rematch as RequestRematch, send_message as SendMessage, send_reaction as SendReaction,
+    start_tournament as StartTournament, stop_watching as StopWatching,
+    unblock_player as UnblockPlayer, watch_game as WatchGame,
 };
+pub use events::{OnDelete, OnInsert, OnReducer, OnUpdate, ReducerFailed, Stdb};
+
+/// Mirrors the server, the rating of a player without a rated game yet
+pub const DEFAULT_RATING: u32 = 1200;
 pub use resources::*;
 pub use systems::*;
 
@@ -32,41 +56,79 @@ pub fn connect_stdb(app: &mut App) {
     let conn_builder = stdb_lifecycle_events!(app, conn_builder);
 
     #[cfg(not(target_arch = "wasm32"))]
-    {
-        let mut conn = conn_builder
-            .build()
-            .expect("Failed to establish connection.");
-        register_callbacks(app, &mut conn);
-        conn.run_threaded();
-        app.insert_resource(NetworkConnection::new(conn));
+    match conn_builder.build() {
+        Ok(mut conn) => {
+            register_callbacks(app, &mut conn);
+            conn.run_threaded();
+            app.insert_resource(NetworkConnection::new(conn));
+        }
+        Err(err) => {
+            // Keep the app usable offline, the table events just never fire
+            warn!("Failed to establish connection: {err}");
+            register_table_events(app);
+        }
     }
 
     #[cfg(target_arch = "wasm32")]
     {
         // The web version needs to poll the connection
-        let connection_task = AsyncComputeTaskPool::get().spawn(async move {
-            conn_builder
-                .build()
-                .await
-                .expect("Failed to establish connection.")
-        });
+        let connection_task =
+            AsyncComputeTaskPool::get().spawn(async move { conn_builder.build().await });
         app.insert_resource(PendingConnection(connection_task));
-
         // Declare all events to be used.
         // We need to subscribe to their callbacks after the connection is polled.
-        // OnEvent<Table>
-        stdb_register_event!(
-            app,
-            OnSubApplied<Vec<LobbyRoom>>,
-            OnInsert<LobbyRoom>,
-            OnDelete<LobbyRoom>,
-            OnInsert<Game>,
-            OnDelete<Game>,
-            OnUpdate<Game>
-        );
+        register_table_events(app);
     }
 }
 
+/// Declares the table events without subscribing to their callbacks
+fn register_table_events(app: &mut App) {
+    // OnEvent<Table>
+    stdb_register_event!(
+        app,
+        OnSubApplied<Vec<LobbyRoom>>,
+        OnInsert<LobbyRoom>,
+        OnDelete<LobbyRoom>,
+        OnInsert<Game>,
+        OnDelete<Game>,
+        OnUpdate<Game>,
+        OnInsert<Tournament>,
+        OnUpdate<Tournament>,
+        OnInsert<TournamentEntrant>,
+        OnUpdate<TournamentEntrant>,
+        OnDelete<TournamentEntrant>,
+        OnInsert<TournamentPairing>,
+        OnUpdate<TournamentPairing>,
+        OnInsert<Arena>,
+        OnUpdate<Arena>,
+        OnInsert<ArenaPlayer>,
+        OnUpdate<ArenaPlayer>,
+        OnInsert<LiveGameSummary>,
+        OnUpdate<LiveGameSummary>,
+        OnDelete<LiveGameSummary>,
+        OnInsert<ChatMessage>,
+        OnInsert<GameReaction>,
+        OnInsert<Rematch>,
+        OnUpdate<Rematch>,
+        OnDelete<Rematch>,
+        OnInsert<JoinRequest>,
+        OnDelete<JoinRequest>,
+        OnInsert<Invitation>,
+        OnDelete<Invitation>,
+        OnInsert<Friendship>,
+        OnUpdate<Friendship>,
+        OnDelete<Friendship>,
+        OnInsert<Presence>,
+        OnUpdate<Presence>,
+        OnInsert<Block>,
+        OnDelete<Block>,
+        OnInsert<Motd>,
+        OnUpdate<Motd>,
+        OnDelete<Motd>
+    );
+    register_reducer_events(app);
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 use bevy::prelude::App as Context;
 #[cfg(target_arch = "wasm32")]
@@ -89,8 +151,78 @@ fn register_callbacks(ctx: &mut Context, conn: &mut DbConnection) {
     stdb_subscribe!(ctx, conn, insert, Game);
     stdb_subscribe!(ctx, conn, delete, Game);
     stdb_subscribe!(ctx, conn, update, Game);
+    stdb_subscribe!(ctx, conn, insert, Tournament);
+    stdb_subscribe!(ctx, conn, update, Tournament);
+    stdb_subscribe!(ctx, conn, insert, TournamentEntrant);
+    stdb_subscribe!(ctx, conn, update, TournamentEntrant);
+    stdb_subscribe!(ctx, conn, delete, TournamentEntrant);
+    stdb_subscribe!(ctx, conn, insert, TournamentPairing);
+    stdb_subscribe!(ctx, conn, update, TournamentPairing);
+    stdb_subscribe!(ctx, conn, insert, Arena);
+    stdb_subscribe!(ctx, conn, update, Arena);
+    stdb_subscribe!(ctx, conn, insert, ArenaPlayer);
+    stdb_subscribe!(ctx, conn, update, ArenaPlayer);
+    stdb_subscribe!(ctx, conn, insert, LiveGameSummary);
+    stdb_subscribe!(ctx, conn, update, LiveGameSummary);
+    stdb_subscribe!(ctx, conn, delete, LiveGameSummary);
+    stdb_subscribe!(ctx, conn, insert, ChatMessage);
+    stdb_subscribe!(ctx, conn, insert, GameReaction);
+    stdb_subscribe!(ctx, conn, insert, Rematch);
+    stdb_subscribe!(ctx, conn, update, Rematch);
+    stdb_subscribe!(ctx, conn, delete, Rematch);
+    stdb_subscribe!(ctx, conn, insert, JoinRequest);
+    stdb_subscribe!(ctx, conn, delete, JoinRequest);
+    stdb_subscribe!(ctx, conn, insert, Invitation);
+    stdb_subscribe!(ctx, conn, delete, Invitation);
+    stdb_subscribe!(ctx, conn, insert, Friendship);
+    stdb_subscribe!(ctx, conn, update, Friendship);
+    stdb_subscribe!(ctx, conn, delete, Friendship);
+    stdb_subscribe!(ctx, conn, insert, Presence);
+    stdb_subscribe!(ctx, conn, update, Presence);
+    stdb_subscribe!(ctx, conn, insert, Block);
+    stdb_subscribe!(ctx, conn, delete, Block);
+    stdb_subscribe!(ctx, conn, insert, Motd);
+    stdb_subscribe!(ctx, conn, update, Motd);
+    stdb_subscribe!(ctx, conn, delete, Motd);
+
+    register_reducer_callbacks(ctx, conn);
 }
 
+// Reducers this client calls, their outcome comes back as `Stdb<OnReducer<<Reducer>Call>>`
+stdb_reducers!(
+    accept_friend(friendship_id: u32),
+    accept_invitation(invitation_id: u32),
+    accept_join_request(request_id: u32),
+    accept_rematch(game_id: String),
+    block_player(target: String),
+    cancel_invitation(invitation_id: u32),
+    challenge_player(target: String, side: SidePreference, rated: bool),
+    create_arena(duration_minutes: u8),
+    create_room(owner_side: SidePreference, rated: bool),
+    create_tournament(format: TournamentFormat, max_entrants: u8),
+    decline_invitation(invitation_id: u32),
+    decline_join_request(request_id: u32),
+    decline_rematch(game_id: String),
+    friend_request(target: String),
+    join_arena(arena_id: u32),
+    join_game(room_id: u32),
+    join_tournament(tournament_id: u32),
+    keep_room_alive(),
+    leave_arena(arena_id: u32),
+    leave_game(game_id: String),
+    leave_room(),
+    mark_cell(game_id: String, cell: u16),
+    play_vs_bot(difficulty: BotDifficulty, side: SidePreference),
+    remove_friend(friendship_id: u32),
+    report_player(game_id: String, reason: ReportReason, text: String),
+    request_rematch(game_id: String),
+    send_message(channel: String, text: String),
+    send_reaction(game_id: String, emote: Emote),
+    start_tournament(tournament_id: u32),
+    unblock_player(block_id: u32),
+    watch_game(game_id: String),
+);
+
 /// Listens on the EventQueue and writes Bevy events
 fn process_network_queue<T: 'static + Send + Sync>(
     maybe_queue: Option<Res<EventQueue<T>>>,
@@ -14,7 +14,7 @@ pub struct NetworkAuth {
 
 #[cfg(target_arch = "wasm32")]
 #[derive(Resource)]
-pub struct PendingConnection(pub bevy::tasks::Task<DbConnection>);
+pub struct PendingConnection(pub bevy::tasks::Task<Result<DbConnection, spacetimedb_sdk::Error>>);
 
 #[derive(Resource, Deref, DerefMut)]
 pub struct NetworkConnection(pub DbConnection);
@@ -4,37 +4,59 @@ use bevy::tasks::futures_lite;
 use spacetimedb_sdk::DbContext as _;
 
 use crate::AppState;
-use crate::resources::{BoardData, FontSpaceGrotesk};
+use crate::resources::{BoardData, BoardSystems, FontSpaceGrotesk, duration_from_turn};
 use crate::ui::{
-    CellMarked, GridCell, JoinGameButton, LobbyPanel, LobbyRoomId, TurnOwnerLabel, UiButtonStyle,
-    colors,
+    CellMarked, GameOverDelay, GridCell, LobbyRoomId, PendingMark, TurnOwnerLabel, end_won_game,
+    populate_lobby_from_cache, refresh_turn_owner_label, render_grid, rollback_pending_mark,
+    short_identity,
 };
 
 use super::{
-    Game, GameState, LobbyRoom, NetworkAuth, NetworkConnection, OnConnect, OnDelete, OnInsert,
-    OnUpdate, Player, Stdb,
+    Game, GameEndReason, GameEndTableAccess, GameState, GameTableAccess, LobbyRoom, MarkCellCall,
+    NetworkAuth, NetworkConnection, OnConnect, OnConnectError, OnDelete, OnInsert, OnReducer,
+    OnUpdate, Player, ReducerFailed, Stdb,
 };
 
 pub fn setup_systems(app: &mut App) {
     super::connect_stdb(app);
 
-    let update_initialization = on_network_connected.run_if(in_state(AppState::Initialization));
+    let is_offline = not(resource_exists::<NetworkConnection>);
+    // The web connection is still pending until polled
+    #[cfg(target_arch = "wasm32")]
+    let is_offline = is_offline.and(not(resource_exists::<super::PendingConnection>));
+
+    let update_initialization = (
+        on_network_connected,
+        on_network_connect_error.run_if(on_event::<Stdb<OnConnectError>>),
+        enter_offline_mode.run_if(is_offline),
+    )
+        .run_if(in_state(AppState::Initialization));
 
     let update_home_screen = (
         on_lobby_room_created.run_if(on_event::<Stdb<OnInsert<LobbyRoom>>>),
         on_lobby_room_removed.run_if(on_event::<Stdb<OnDelete<LobbyRoom>>>),
-        on_game_created.run_if(on_event::<Stdb<OnInsert<Game>>>),
     )
         .run_if(in_state(AppState::HomeScreen));
 
-    let update_lobby_sceen = (on_game_created.run_if(on_event::<Stdb<OnInsert<Game>>>),)
-        .run_if(in_state(AppState::LobbyScreen));
+    let update_lobby_sceen =
+        (on_own_lobby_room_expired.run_if(on_event::<Stdb<OnDelete<LobbyRoom>>>),)
+            .run_if(in_state(AppState::LobbyScreen));
+
+    // A game can start from any screen outside of one: an accepted room or challenge, the next
+    // tournament or arena game, a rematch, a watched game once the server registered the spectator
+    let update_out_of_game = (on_game_created.run_if(on_event::<Stdb<OnInsert<Game>>>),).run_if(
+        not(in_state(AppState::Initialization))
+            .and(not(in_state(AppState::GameSetup)))
+            .and(not(in_state(AppState::GameInProgress))),
+    );
 
     let update_game_in_progress = (
         on_game_updated.run_if(on_event::<Stdb<OnUpdate<Game>>>),
+        on_mark_cell_result.run_if(on_event::<Stdb<OnReducer<MarkCellCall>>>),
         on_game_deleted.run_if(on_event::<Stdb<OnDelete<Game>>>),
     )
-        .run_if(in_state(AppState::GameInProgress));
+        // The game is over already while its winning line is on show
+        .run_if(in_state(AppState::GameInProgress).and(not(resource_exists::<GameOverDelay>)));
 
     #[cfg(target_arch = "wasm32")]
     app.add_systems(
@@ -45,9 +67,11 @@ pub fn setup_systems(app: &mut App) {
     app.add_systems(
         Update,
         (
+            log_reducer_failures.run_if(on_event::<Stdb<ReducerFailed>>),
             update_initialization,
             update_home_screen,
             update_lobby_sceen,
+            update_out_of_game,
             update_game_in_progress,
         ),
     );
@@ -62,13 +86,25 @@ fn poll_pending_connection(
         return;
     };
 
-    if let Some(mut conn) =
-        futures_lite::future::block_on(futures_lite::future::poll_once(&mut pending.0))
-    {
-        super::register_callbacks(&mut cmds, &mut conn);
-        conn.run_background();
-        cmds.insert_resource(NetworkConnection::new(conn));
-        cmds.remove_resource::<super::PendingConnection>();
+    match futures_lite::future::block_on(futures_lite::future::poll_once(&mut pending.0)) {
+        Some(Ok(mut conn)) => {
+            super::register_callbacks(&mut cmds, &mut conn);
+            conn.run_background();
+            cmds.insert_resource(NetworkConnection::new(conn));
+            cmds.remove_resource::<super::PendingConnection>();
+        }
+        Some(Err(err)) => {
+            warn!("Failed to establish connection: {err}");
+            cmds.remove_resource::<super::PendingConnection>();
+        }
+        None => {}
+    }
+}
+
+pub fn log_reducer_failures(mut reducer_failed_ev: EventReader<Stdb<ReducerFailed>>) {
+    for Stdb(failure) in reducer_failed_ev.read() {
+        let code = failure.code.map_or("unknown", |code| code.as_str());
+        warn!("`{}` failed ({code}): {}", failure.reducer, failure.message);
     }
 }
 
@@ -93,6 +129,36 @@ pub fn on_network_connected(
             "SELECT * FROM game WHERE x_player = '{}' OR o_player = '{}'",
             identity, identity
         ));
+        // The game being watched, if any
+        let _ = connection.subscription_builder().subscribe(format!(
+            "SELECT game.* FROM game JOIN spectator ON game.id = spectator.game_id WHERE spectator.identity = '{}'",
+            identity
+        ));
+        let _ = connection.subscription_builder().subscribe([
+            "SELECT * FROM tournament",
+            "SELECT * FROM tournament_entrant",
+            "SELECT * FROM tournament_pairing",
+            "SELECT * FROM arena",
+            "SELECT * FROM arena_player",
+            "SELECT * FROM live_game_summary",
+            // Only the lobby and the games this client plays or watches are visible
+            "SELECT * FROM chat_message",
+            "SELECT * FROM game_reaction",
+            "SELECT * FROM rematch",
+            "SELECT * FROM join_request",
+            "SELECT * FROM invitation",
+            "SELECT * FROM friendship",
+            "SELECT * FROM presence",
+            "SELECT * FROM block",
+            "SELECT * FROM motd",
+            // Only the games this client played
+            "SELECT * FROM game_end",
+        ]);
+        // Rooms are sorted by how close their owner's rating is to this one
+        let _ = connection.subscription_builder().subscribe(format!(
+            "SELECT * FROM player_rating WHERE identity = '{}'",
+            identity
+        ));
 
         #[cfg(target_arch = "wasm32")]
         {
@@ -110,110 +176,37 @@ pub fn on_network_connected(
     }
 }
 
+pub fn on_network_connect_error(
+    mut cmds: Commands,
+    mut on_connect_error_ev: EventReader<Stdb<OnConnectError>>,
+) {
+    for Stdb(OnConnectError(err)) in on_connect_error_ev.read() {
+        warn!("Connection error: {err}");
+        cmds.remove_resource::<NetworkConnection>();
+    }
+}
+
+/// Without a connection only the games against the computer are available
+pub fn enter_offline_mode(mut game_state: ResMut<NextState<AppState>>) {
+    info!("Playing offline");
+    game_state.set(AppState::HomeScreen);
+}
+
+/// Own rooms lead to the waiting screen, anyone else's goes through the lobby filter
 pub fn on_lobby_room_created(
     mut cmds: Commands,
     mut new_lobby_room_ev: EventReader<Stdb<OnInsert<LobbyRoom>>>,
-    mut lobby_panel_q: Query<(Entity, &mut ScrollPosition), With<LobbyPanel>>,
-    lobby_rooms_q: Query<&Children, With<LobbyPanel>>,
-    font: Res<FontSpaceGrotesk>,
     network_auth: Res<NetworkAuth>,
     mut game_state: ResMut<NextState<AppState>>,
 ) {
-    let Ok((lobby_entity, mut scroll_position)) = lobby_panel_q.single_mut() else {
-        warn!("LobbyRoom new room early return! No LobbyPanel!");
-        return;
-    };
-    let Some(new_room) = new_lobby_room_ev.read().next() else {
-        warn!("LobbyRoom new room early return! System triggered without event?");
-        return;
-    };
-
-    if network_auth.identity == new_room.owner {
+    if new_lobby_room_ev
+        .read()
+        .any(|Stdb(OnInsert(new_room))| new_room.owner == network_auth.identity)
+    {
         game_state.set(AppState::LobbyScreen);
         return;
     }
-
-    let (idx, room_id, game_id) = (
-        lobby_rooms_q.iter().count(),
-        new_room.id,
-        new_room.game_id.clone(),
-    );
-
-    let mut join_button = UiButtonStyle {
-        color: colors::GREEN_YELLOW.into(),
-        border_color: colors::DEEP_PINK.into(),
-        text_color: colors::DARK_VIOLET.into(),
-    };
-    if idx % 2 != 0 {
-        join_button.color = colors::GOLD.into();
-    };
-
-    let lobby_room = cmds
-        .spawn((
-            LobbyRoomId(room_id),
-            Node {
-                height: Val::Percent(95.),
-                width: Val::Percent(98.),
-                display: Display::Grid,
-                column_gap: Val::Px(2.),
-                overflow: Overflow::clip(),
-                grid_template_columns: vec![
-                    RepeatedGridTrack::fr(1, 3.),
-                    RepeatedGridTrack::fr(1, 2.),
-                ],
-                grid_auto_flow: GridAutoFlow::Column,
-                align_items: AlignItems::Center,
-                justify_items: JustifyItems::Center,
-                border: UiRect::bottom(Val::Px(2.)),
-                ..default()
-            },
-            BackgroundColor(colors::DARK_VIOLET.with_alpha(0.01).into()),
-            BorderColor(colors::DODGER_BLUE.with_alpha(0.2).into()),
-            BorderRadius::all(Val::Px(5.0)),
-        ))
-        .with_children(|lobby_row| {
-            lobby_row.spawn((
-                Label,
-                Text::new(game_id),
-                TextFont {
-                    font: font.clone(),
-                    font_size: 16.0,
-                    ..default()
-                },
-                TextColor(colors::GOLD.into()),
-            ));
-            lobby_row
-                .spawn((
-                    JoinGameButton(room_id),
-                    Button,
-                    join_button,
-                    Node {
-                        height: Val::Percent(90.0),
-                        min_width: Val::Px(50.),
-                        border: UiRect::all(Val::Px(1.0)),
-                        align_items: AlignItems::Center,
-                        justify_content: JustifyContent::Center,
-                        grid_column: GridPlacement::start(2),
-                        ..default()
-                    },
-                    BorderRadius::all(Val::Px(5.0)),
-                    BorderColor(join_button.border_color),
-                    BackgroundColor(join_button.color),
-                ))
-                .with_child((
-                    Text::new("Join"),
-                    TextFont {
-                        font: font.clone(),
-                        font_size: 18.0,
-                        ..default()
-                    },
-                    TextColor(join_button.text_color),
-                ));
-        })
-        .id();
-    cmds.entity(lobby_entity).add_child(lobby_room);
-
-    scroll_position.offset_y = 100000.;
+    cmds.run_system_cached(populate_lobby_from_cache);
 }
 
 pub fn on_lobby_room_removed(
@@ -234,10 +227,29 @@ pub fn on_lobby_room_removed(
     Ok(())
 }
 
+/// The server closed the room before anyone joined, back to the lobby
+pub fn on_own_lobby_room_expired(
+    mut lobby_room_del_ev: EventReader<Stdb<OnDelete<LobbyRoom>>>,
+    connection: Res<NetworkConnection>,
+    network_auth: Res<NetworkAuth>,
+    mut game_state: ResMut<NextState<AppState>>,
+) {
+    for Stdb(OnDelete(room)) in lobby_room_del_ev.read() {
+        // A room also goes away once its game starts
+        if room.owner == network_auth.identity
+            && connection.db().game().id().find(&room.game_id).is_none()
+        {
+            game_state.set(AppState::HomeScreen);
+        }
+    }
+}
+
 pub fn on_game_created(
     mut cmds: Commands,
     mut game_created_ev: EventReader<Stdb<OnInsert<Game>>>,
     network_auth: Res<NetworkAuth>,
+    maybe_board: Option<Res<BoardData>>,
+    board_systems: Res<BoardSystems>,
     mut game_state: ResMut<NextState<AppState>>,
 ) {
     let Some(game) = game_created_ev.read().next() else {
@@ -245,14 +257,32 @@ pub fn on_game_created(
         return;
     };
 
-    let board_data = {
-        if network_auth.identity == game.x_player {
-            BoardData::new(Player::X, game.id.clone())
-        } else {
-            BoardData::new(Player::O, game.id.clone())
-        }
+    // Still looking at the previous board
+    if maybe_board.is_some() {
+        cmds.run_system(board_systems["clear_board"]);
+    }
+
+    // Not one of the players, this client is watching the game
+    if network_auth.identity != game.x_player && network_auth.identity != game.o_player {
+        info!("Watching game '{}'", game.id);
+        cmds.insert_resource(BoardData::spectating(
+            game,
+            short_identity(&game.x_player),
+            short_identity(&game.o_player),
+        ));
+        game_state.set(AppState::GameSetup);
+        return;
+    }
+
+    // The server resolves the sides on join, the whole board is driven from the assigned one
+    let assigned_side = if network_auth.identity == game.x_player {
+        Player::X
+    } else {
+        Player::O
     };
-    cmds.insert_resource(board_data);
+    info!("Game '{}' created, playing as {:?}", game.id, assigned_side);
+
+    cmds.insert_resource(BoardData::new(assigned_side, game.id.clone()));
     game_state.set(AppState::GameSetup);
 }
 
@@ -260,108 +290,104 @@ pub fn on_game_deleted(
     mut game_deleted_ev: EventReader<Stdb<OnDelete<Game>>>,
     mut game_board: ResMut<BoardData>,
     mut game_state: ResMut<NextState<AppState>>,
+    connection: Res<NetworkConnection>,
 ) {
-    let Some(_game) = game_deleted_ev.read().next() else {
-        warn!("Game Deleted: none early return");
+    let board_id = game_board.id();
+    let Some(Stdb(OnDelete(game))) = game_deleted_ev
+        .read()
+        .find(|Stdb(OnDelete(game))| game.id == board_id)
+    else {
         return;
     };
 
-    // If this client received the on_delete event we assume the other client disconnected
-    game_board.set_result_network_primary();
+    // The server leaves the reason behind for the players, spectators only see the game go
+    let game_end = connection.db().game_end().game_id().find(&game.id);
+    match game_end.map(|end| (end.reason, end.winner)) {
+        Some((GameEndReason::Terminated, _)) => game_board.set_result_terminated(),
+        Some((GameEndReason::Banned, Some(winner))) if winner != game_board.network_primary => {
+            game_board.set_result_banned()
+        }
+        Some((_, Some(winner))) => game_board.set_result_winner(&winner),
+        Some((_, None)) => game_board.set_result_draw(),
+        None => game_board.set_result_abandoned(),
+    }
     game_state.set(AppState::GameOverScreen);
 }
 
+/// Takes back the pending mark when the server turned the move down
+pub fn on_mark_cell_result(
+    mut cmds: Commands,
+    mut pending_cell_q: Query<(Entity, &mut BackgroundColor, &GridCell), With<PendingMark>>,
+    mut mark_cell_ev: EventReader<Stdb<OnReducer<MarkCellCall>>>,
+    mut game_board: ResMut<BoardData>,
+) {
+    for Stdb(OnReducer { call, result }) in mark_cell_ev.read() {
+        if result.is_ok()
+            || call.game_id != game_board.id()
+            || game_board.pending_cell != Some(call.cell)
+        {
+            continue;
+        }
+        game_board.pending_cell = None;
+        if let Some((entity_id, mut color, _)) =
+            pending_cell_q.iter_mut().find(|(_, _, c)| c.0 == call.cell)
+        {
+            rollback_pending_mark(&mut cmds, entity_id, &mut color);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
 pub fn on_game_updated(
     mut cmds: Commands,
-    mut cell_q: Query<(Entity, &mut BackgroundColor, &GridCell), Without<CellMarked>>,
+    mut cell_q: Query<(
+        Entity,
+        &GridCell,
+        &mut BackgroundColor,
+        &mut BorderColor,
+        Has<CellMarked>,
+        Has<PendingMark>,
+    )>,
     mut turn_owner_label_q: Query<(&mut Text, &mut TextColor), With<TurnOwnerLabel>>,
     mut game_update_ev: EventReader<Stdb<OnUpdate<Game>>>,
     mut game_board: ResMut<BoardData>,
     mut game_state: ResMut<NextState<AppState>>,
     font: Res<FontSpaceGrotesk>,
 ) -> Result {
-    let Stdb(OnUpdate { old, new }) = game_update_ev
+    // Every row is the whole game, the latest one of the batch is all the board needs
+    let Some(new) = game_update_ev
         .read()
-        .next()
-        .ok_or(BevyError::from("StdbEvent not found"))?;
-
-    if (old.turn_owner != new.turn_owner) && !new.time_expired {
-        // No time_expired, we assume the old turn_owner has made a valid move to trigger the update
-        let (cell_marked_id, letter, bg_color, text_color) = match old.turn_owner {
-            Player::X => {
-                game_board.x_mask = new.x_mask;
-                (
-                    old.x_mask ^ new.x_mask,
-                    "X",
-                    colors::GOLD.into(),
-                    colors::DARK_VIOLET.into(),
-                )
-            }
-            Player::O => {
-                game_board.o_mask = new.o_mask;
-                (
-                    old.o_mask ^ new.o_mask,
-                    "O",
-                    colors::DEEP_PINK.into(),
-                    colors::GREEN_YELLOW.into(),
-                )
-            }
-        };
-
-        let (entity_id, mut color, _) = cell_q
-            .iter_mut()
-            .find(|(_, _, c)| c.0 == cell_marked_id)
-            .ok_or(BevyError::from(
-            "CellEntity '{cell_marked_id}' modified but was not found",
-        ))?;
-        *color = bg_color;
-        let mut e = cmds.entity(entity_id);
-        e.insert(CellMarked);
-        e.with_child((
-            Text::new(letter),
-            TextFont {
-                font: font.clone(),
-                font_size: 40.0,
-                ..default()
-            },
-            TextColor(text_color),
-        ));
-    }
+        .map(|Stdb(OnUpdate { new, .. })| new)
+        .filter(|game| game.id == game_board.id())
+        .last()
+    else {
+        return Ok(());
+    };
+
+    game_board.x_mask = new.x_mask;
+    game_board.o_mask = new.o_mask;
+    game_board.last_move = new.last_move;
+    game_board.moves = new.moves.clone();
+    render_grid(&mut cmds, &mut cell_q, &mut game_board, &font);
 
     match new.state {
         GameState::InProgress => {
+            game_board.turn = new.turn;
             game_board.turn_duration = duration_from_turn(new.turn);
             game_board.turn_owner = new.turn_owner;
 
             let (mut turn_owner_label, mut text_color) = turn_owner_label_q.single_mut()?;
-            *turn_owner_label = {
-                if game_board.turn_owner == game_board.network_primary {
-                    *text_color = Color::WHITE.into();
-                    "Your Turn!"
-                } else {
-                    *text_color = colors::GOLD.into();
-                    match game_board.turn_owner {
-                        Player::X => "X's Turn.",
-                        Player::O => "O's Turn",
-                    }
-                }
-            }
-            .into();
+            refresh_turn_owner_label(&mut turn_owner_label, &mut text_color, &game_board);
         }
         GameState::Draw => {
             game_board.set_result_draw();
             game_state.set(AppState::GameOverScreen);
         }
         GameState::Winner(player) => {
+            game_board.winning_mask = new.winning_mask;
             game_board.set_result_winner(&player);
-            game_state.set(AppState::GameOverScreen);
+            end_won_game(&mut cmds, &mut game_state, &game_board);
         }
     }
     Ok(())
 }
-
-fn duration_from_turn(n: u8) -> f32 {
-    let decrement_1 = [1, 2, 4].iter().filter(|&&x| x <= n).count() as f32;
-    let decrement_half = [6, 8].iter().filter(|&&x| x <= n).count() as f32;
-    5.0 - decrement_1 - decrement_half * 0.5
-}
@@ -0,0 +1,21 @@
+use rand::seq::IndexedRandom;
+use tictactoe_rules::{Difficulty, candidate_cells};
+
+use crate::network::BotDifficulty;
+
+impl From<BotDifficulty> for Difficulty {
+    fn from(difficulty: BotDifficulty) -> Self {
+        match difficulty {
+            BotDifficulty::Easy => Difficulty::Easy,
+            BotDifficulty::Medium => Difficulty::Medium,
+            BotDifficulty::Hard => Difficulty::Hard,
+        }
+    }
+}
+
+/// Picks the computer's next cell with the same moves the server's bot would consider.
+pub fn pick_cell(difficulty: BotDifficulty, own: u16, other: u16) -> Option<u16> {
+    candidate_cells(difficulty.into(), own, other)
+        .choose(&mut rand::rng())
+        .copied()
+}
@@ -0,0 +1,96 @@
+mod ai;
+mod systems;
+
+use bevy::prelude::*;
+use tictactoe_rules::{all_lines_blocked, winning_line};
+
+use crate::AppState;
+use crate::network::{GameState, Player, SidePreference};
+use crate::resources::{BoardData, duration_from_turn};
+use crate::ui::GameOverDelay;
+
+pub use systems::*;
+
+/// A cell marked by whoever owns the turn in a game played without a server
+#[derive(Event, Deref)]
+pub struct LocalMove(pub u16);
+
+/// Delay before the computer answers, so its moves don't land instantly
+#[derive(Resource, Deref, DerefMut)]
+pub struct ComputerThinking(Timer);
+
+impl Default for ComputerThinking {
+    fn default() -> Self {
+        Self(Timer::from_seconds(0.6, TimerMode::Once))
+    }
+}
+
+pub fn setup_systems(app: &mut App) {
+    app.add_event::<LocalMove>()
+        .init_resource::<ComputerThinking>();
+
+    app.add_systems(
+        Update,
+        (
+            computer_turn,
+            apply_local_moves.run_if(on_event::<LocalMove>),
+        )
+            .chain()
+            .run_if(in_state(AppState::GameInProgress).and(not(resource_exists::<GameOverDelay>))),
+    );
+    app.add_systems(OnExit(AppState::GameInProgress), reset_computer_thinking);
+}
+
+/// Resolves a side preference on this device, a random preference is a fair coin flip
+pub fn resolve_local_side(preference: SidePreference) -> Player {
+    match preference {
+        SidePreference::X => Player::X,
+        SidePreference::O => Player::O,
+        SidePreference::Random => {
+            if rand::random_bool(0.5) {
+                Player::X
+            } else {
+                Player::O
+            }
+        }
+    }
+}
+
+/// Marks `cell` for the turn owner, then either ends the game or hands the turn over.
+pub fn play_local_cell(board: &mut BoardData, cell: u16) -> GameState {
+    let curr_player_cells = match board.turn_owner {
+        Player::X => {
+            board.x_mask |= cell;
+            board.x_mask
+        }
+        Player::O => {
+            board.o_mask |= cell;
+            board.o_mask
+        }
+    };
+
+    if let Some(mask) = winning_line(curr_player_cells) {
+        board.winning_mask = Some(mask);
+        return GameState::Winner(board.turn_owner);
+    }
+    if all_lines_blocked(board.x_mask, board.o_mask) {
+        return GameState::Draw;
+    }
+
+    board.turn += 1;
+    swap_turn_owner(board);
+    GameState::InProgress
+}
+
+/// The turn owner ran out of time, the opponent plays the same turn.
+pub fn expire_local_turn(board: &mut BoardData) {
+    swap_turn_owner(board);
+}
+
+fn swap_turn_owner(board: &mut BoardData) {
+    board.turn_owner = match board.turn_owner {
+        Player::X => Player::O,
+        Player::O => Player::X,
+    };
+    board.turn_duration = duration_from_turn(board.turn);
+}
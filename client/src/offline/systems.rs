@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::network::{GameState, Player};
+use crate::resources::{BoardData, FontSpaceGrotesk, GameMode};
+use crate::ui::{
+    CellMarked, GridCell, TurnOwnerLabel, end_won_game, mark_grid_cell, refresh_turn_owner_label,
+};
+
+use super::{ComputerThinking, LocalMove, ai, play_local_cell};
+
+pub fn computer_turn(
+    mut local_move_writer: EventWriter<LocalMove>,
+    mut thinking: ResMut<ComputerThinking>,
+    board: Res<BoardData>,
+    time: Res<Time>,
+) {
+    let GameMode::VsComputer(difficulty) = board.mode else {
+        return;
+    };
+    if board.is_primary_turn() {
+        thinking.reset();
+        return;
+    }
+    if !thinking.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let (own, other) = match board.turn_owner {
+        Player::X => (board.x_mask, board.o_mask),
+        Player::O => (board.o_mask, board.x_mask),
+    };
+    if let Some(cell) = ai::pick_cell(difficulty, own, other) {
+        local_move_writer.write(LocalMove(cell));
+    }
+}
+
+pub fn apply_local_moves(
+    mut cmds: Commands,
+    mut cell_q: Query<(Entity, &mut BackgroundColor, &GridCell), Without<CellMarked>>,
+    mut turn_owner_label_q: Query<(&mut Text, &mut TextColor), With<TurnOwnerLabel>>,
+    mut local_move_ev: EventReader<LocalMove>,
+    mut game_board: ResMut<BoardData>,
+    mut game_state: ResMut<NextState<AppState>>,
+    font: Res<FontSpaceGrotesk>,
+) -> Result {
+    for &LocalMove(cell) in local_move_ev.read() {
+        if !game_board.cell_is_free(cell) {
+            continue;
+        }
+        let Some((entity_id, mut color, _)) = cell_q.iter_mut().find(|(_, _, c)| c.0 == cell)
+        else {
+            continue;
+        };
+        mark_grid_cell(
+            &mut cmds,
+            entity_id,
+            &mut color,
+            game_board.turn_owner,
+            &font,
+        );
+
+        match play_local_cell(&mut game_board, cell) {
+            GameState::InProgress => {
+                let (mut turn_owner_label, mut text_color) = turn_owner_label_q.single_mut()?;
+                refresh_turn_owner_label(&mut turn_owner_label, &mut text_color, &game_board);
+            }
+            GameState::Draw => {
+                game_board.set_result_draw();
+                game_state.set(AppState::GameOverScreen);
+                break;
+            }
+            GameState::Winner(player) => {
+                game_board.set_result_winner(&player);
+                end_won_game(&mut cmds, &mut game_state, &game_board);
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn reset_computer_thinking(mut thinking: ResMut<ComputerThinking>) {
+    thinking.reset();
+}
@@ -0,0 +1,48 @@
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::network::{NetworkConnection, OnDelete, OnInsert, OnUpdate, Rematch, Stdb};
+use crate::ui::game_over_screen;
+
+pub use systems::*;
+
+/// Asks for a rematch of `game_id`, or agrees to the opponent's
+#[derive(Component)]
+pub struct RematchButton {
+    pub game_id: String,
+}
+
+#[derive(Component, Default)]
+pub struct RematchStatus {
+    /// Set once the server opened the rematch, its removal then means the opponent left
+    pub offered: bool,
+}
+
+pub fn setup_systems(app: &mut App) {
+    app.add_systems(
+        OnEnter(AppState::GameOverScreen),
+        (spawn_rematch_controls, refresh_rematch_controls)
+            .chain()
+            .after(game_over_screen)
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+    app.add_systems(
+        Update,
+        (
+            rematch_button_interaction,
+            refresh_rematch_controls.run_if(
+                on_event::<Stdb<OnInsert<Rematch>>>
+                    .or(on_event::<Stdb<OnUpdate<Rematch>>>)
+                    .or(on_event::<Stdb<OnDelete<Rematch>>>),
+            ),
+        )
+            .run_if(in_state(AppState::GameOverScreen))
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+    app.add_systems(
+        OnExit(AppState::GameOverScreen),
+        withdraw_rematch.run_if(resource_exists::<NetworkConnection>),
+    );
+}
@@ -0,0 +1,196 @@
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, Table};
+
+use crate::network::{
+    AcceptRematch, DeclineRematch, NetworkAuth, NetworkConnection, Rematch, RematchTableAccess,
+    RequestRematch,
+};
+use crate::resources::{BoardData, FontSpaceGrotesk, GameMode};
+use crate::ui::{GameOverScreen, UiButtonStyle, colors};
+
+use super::{RematchButton, RematchStatus};
+
+/// Adds the rematch status and button above "Go Back", the button shows up with the offer
+pub fn spawn_rematch_controls(
+    mut cmds: Commands,
+    game_over_screen_q: Query<Entity, With<GameOverScreen>>,
+    board: Res<BoardData>,
+    font: Res<FontSpaceGrotesk>,
+) -> Result {
+    // Only two people playing each other can agree on a rematch
+    if board.mode != GameMode::Network {
+        return Ok(());
+    }
+    let game_over_screen = game_over_screen_q.single()?;
+
+    let status = cmds
+        .spawn((
+            RematchStatus::default(),
+            Label,
+            Text::new(""),
+            TextFont {
+                font: font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(colors::DODGER_BLUE.into()),
+        ))
+        .id();
+
+    let rematch_button_style = UiButtonStyle {
+        color: colors::GREEN_YELLOW.into(),
+        border_color: colors::DODGER_BLUE.into(),
+        text_color: colors::DARK_VIOLET.into(),
+    };
+    let button = cmds
+        .spawn((
+            RematchButton {
+                game_id: board.id(),
+            },
+            Button,
+            rematch_button_style,
+            Node {
+                display: Display::None,
+                width: Val::Percent(60.0),
+                height: Val::Px(30.0),
+                border: UiRect::all(Val::Px(1.0)),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BorderRadius::all(Val::Px(10.0)),
+            BorderColor(rematch_button_style.border_color),
+            BackgroundColor(rematch_button_style.color),
+        ))
+        .with_child((
+            Text::new("Rematch"),
+            TextFont {
+                font: font.clone(),
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(rematch_button_style.text_color),
+        ))
+        .id();
+
+    // "Match Over" and the result come first
+    cmds.entity(game_over_screen)
+        .insert_children(2, &[status, button]);
+    Ok(())
+}
+
+/// Follows the rematch offer of the finished game
+pub fn refresh_rematch_controls(
+    mut status_q: Query<(&mut Text, &mut RematchStatus)>,
+    mut button_q: Query<(&RematchButton, &mut Node, &Children)>,
+    mut button_text_q: Query<&mut Text, Without<RematchStatus>>,
+    connection: Res<NetworkConnection>,
+    network_auth: Res<NetworkAuth>,
+) -> Result {
+    let Ok((rematch_button, mut button_node, children)) = button_q.single_mut() else {
+        return Ok(());
+    };
+    let (mut status_text, mut status) = status_q.single_mut()?;
+    let mut button_text = button_text_q.get_mut(children[0])?;
+
+    let Some(rematch) = connection
+        .db()
+        .rematch()
+        .game_id()
+        .find(&rematch_button.game_id)
+    else {
+        if status.offered {
+            **status_text = "Opponent left".to_string();
+            button_node.display = Display::None;
+        }
+        return Ok(());
+    };
+    status.offered = true;
+
+    let (mine, theirs) = wants(&rematch, &network_auth);
+    let (status_line, button_label) = match (mine, theirs) {
+        (false, false) => ("", Some("Rematch")),
+        (true, _) => ("Waiting for opponent...", None),
+        (false, true) => ("Opponent wants a rematch", Some("Accept")),
+    };
+    **status_text = status_line.to_string();
+    match button_label {
+        Some(label) => {
+            **button_text = label.to_string();
+            button_node.display = Display::Flex;
+        }
+        None => button_node.display = Display::None,
+    }
+    Ok(())
+}
+
+#[allow(clippy::type_complexity)]
+pub fn rematch_button_interaction(
+    mut interaction_query: Query<
+        (
+            &RematchButton,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut TextColor>,
+    conn: Res<NetworkConnection>,
+    network_auth: Res<NetworkAuth>,
+) -> Result {
+    for (rematch_button, button_style, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0])?;
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = button_style.text_color.into();
+
+                let game_id = rematch_button.game_id.clone();
+                let opponent_asked = conn
+                    .db()
+                    .rematch()
+                    .game_id()
+                    .find(&game_id)
+                    .is_some_and(|r| wants(&r, &network_auth).1);
+                if opponent_asked {
+                    conn.reducers.accept_rematch(game_id)?;
+                } else {
+                    conn.reducers.request_rematch(game_id)?;
+                }
+            }
+            Interaction::Hovered => {
+                *color = button_style.text_color.into();
+                *text_color = button_style.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = button_style.color.into();
+                *text_color = button_style.text_color.into();
+                *border_color = button_style.border_color.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Leaving the game-over screen turns down whatever rematch is still open
+pub fn withdraw_rematch(connection: Res<NetworkConnection>) -> Result {
+    for rematch in connection.db().rematch().iter() {
+        connection.reducers.decline_rematch(rematch.game_id)?;
+    }
+    Ok(())
+}
+
+/// Whether this client and its opponent asked for the rematch
+fn wants(rematch: &Rematch, network_auth: &NetworkAuth) -> (bool, bool) {
+    if network_auth.identity == rematch.x_player {
+        (rematch.x_wants, rematch.o_wants)
+    } else {
+        (rematch.o_wants, rematch.x_wants)
+    }
+}
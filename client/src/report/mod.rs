@@ -0,0 +1,36 @@
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::network::{NetworkConnection, ReportReason};
+use crate::ui::game_over_screen;
+
+pub use systems::*;
+
+/// Row at the bottom of the game-over screen, swapped for the reasons once opened
+#[derive(Component)]
+pub struct ReportControls {
+    pub game_id: String,
+}
+
+#[derive(Component, Clone, Copy)]
+pub enum ReportButton {
+    Open,
+    Reason(ReportReason),
+}
+
+pub fn setup_systems(app: &mut App) {
+    app.add_systems(
+        OnEnter(AppState::GameOverScreen),
+        spawn_report_controls
+            .after(game_over_screen)
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+    app.add_systems(
+        Update,
+        report_button_interaction
+            .run_if(in_state(AppState::GameOverScreen))
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+}
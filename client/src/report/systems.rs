@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+
+use crate::network::{NetworkConnection, ReportPlayer, ReportReason};
+use crate::resources::{BoardData, FontSpaceGrotesk, GameMode};
+use crate::ui::{GameOverScreen, UiButtonStyle, colors, spawn_small_button};
+
+use super::{ReportButton, ReportControls};
+
+const REASONS: [(ReportReason, &str); 4] = [
+    (ReportReason::Cheating, "Cheat"),
+    (ReportReason::Harassment, "Abuse"),
+    (ReportReason::Stalling, "Stall"),
+    (ReportReason::Other, "Other"),
+];
+
+/// Adds the "Report" button below "Go Back", only the opponent of a network game can be reported
+pub fn spawn_report_controls(
+    mut cmds: Commands,
+    game_over_screen_q: Query<Entity, With<GameOverScreen>>,
+    board: Res<BoardData>,
+    font: Res<FontSpaceGrotesk>,
+) -> Result {
+    if board.mode != GameMode::Network {
+        return Ok(());
+    }
+    let game_over_screen = game_over_screen_q.single()?;
+    cmds.entity(game_over_screen).with_children(|parent| {
+        parent
+            .spawn((
+                ReportControls {
+                    game_id: board.id(),
+                },
+                Node {
+                    column_gap: Val::Px(2.),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+            ))
+            .with_children(|controls| {
+                spawn_small_button(controls, &font, ReportButton::Open, "Report");
+            });
+    });
+    Ok(())
+}
+
+#[allow(clippy::type_complexity)]
+pub fn report_button_interaction(
+    mut cmds: Commands,
+    mut interaction_query: Query<
+        (
+            &ReportButton,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut TextColor>,
+    controls_q: Query<(Entity, &ReportControls)>,
+    conn: Res<NetworkConnection>,
+    font: Res<FontSpaceGrotesk>,
+) -> Result {
+    for (report_button, button_style, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0])?;
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = button_style.text_color.into();
+                let (controls, report_controls) = controls_q.single()?;
+                match report_button {
+                    ReportButton::Open => {
+                        cmds.entity(controls)
+                            .despawn_related::<Children>()
+                            .with_children(|controls| {
+                                for (reason, label) in REASONS {
+                                    spawn_small_button(
+                                        controls,
+                                        &font,
+                                        ReportButton::Reason(reason),
+                                        label,
+                                    );
+                                }
+                            });
+                    }
+                    ReportButton::Reason(reason) => {
+                        conn.reducers.report_player(
+                            report_controls.game_id.clone(),
+                            *reason,
+                            String::new(),
+                        )?;
+                        cmds.entity(controls)
+                            .despawn_related::<Children>()
+                            .with_child((
+                                Text::new("Reported, thanks"),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(colors::DODGER_BLUE.into()),
+                            ));
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *color = button_style.text_color.into();
+                *text_color = button_style.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = button_style.color.into();
+                *text_color = button_style.text_color.into();
+                *border_color = button_style.border_color.into();
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,33 +1,140 @@
 use bevy::prelude::*;
 
-use crate::network::Player;
+use crate::network::{BotDifficulty, Game, Player};
+use crate::ui::colors;
+
+/// Who the board is played against and who enforces the rules
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GameMode {
+    /// Moves go through the server, the board follows the `Game` row updates
+    Network,
+    /// Played on this device against the local AI, no connection needed
+    VsComputer(BotDifficulty),
+    /// Two players taking turns on this device
+    HotSeat,
+    /// Following somebody else's game, the board is read-only
+    Spectating,
+}
 
 #[derive(Resource)]
 pub struct BoardData {
+    pub mode: GameMode,
     pub network_primary: Player,
     pub turn_owner: Player,
     pub x_mask: u16,
     pub o_mask: u16,
     pub result: String,
+    pub turn: u8,
     pub turn_duration: f32,
+    /// Cell this client marked and the server has yet to confirm
+    pub pending_cell: Option<u16>,
+    /// Cell of the latest move, as reported by the server
+    pub last_move: Option<u16>,
+    /// Cells in the order they were marked, spectators see them numbered
+    pub moves: Vec<u16>,
+    /// Cells of the line that won the game
+    pub winning_mask: Option<u16>,
+    /// Labels of the X and O players, shown to spectators
+    pub player_labels: Option<(String, String)>,
     game_id: String,
 }
 
 impl BoardData {
     pub fn new(network_primary: Player, game_id: String) -> Self {
         Self {
+            mode: GameMode::Network,
             network_primary,
             turn_owner: Player::X,
             x_mask: 0,
             o_mask: 0,
             result: "".to_string(),
-            turn_duration: 5.,
+            turn: 0,
+            turn_duration: duration_from_turn(0),
+            pending_cell: None,
+            last_move: None,
+            moves: Vec::new(),
+            winning_mask: None,
+            player_labels: None,
             game_id,
         }
     }
 
+    pub fn vs_computer(player: Player, difficulty: BotDifficulty) -> Self {
+        Self {
+            mode: GameMode::VsComputer(difficulty),
+            ..Self::new(player, "offline".to_string())
+        }
+    }
+
+    pub fn hot_seat() -> Self {
+        Self {
+            mode: GameMode::HotSeat,
+            ..Self::new(Player::X, "hot_seat".to_string())
+        }
+    }
+
+    /// Joins a game already under way, the board starts from its current state
+    pub fn spectating(game: &Game, x_label: String, o_label: String) -> Self {
+        Self {
+            mode: GameMode::Spectating,
+            turn_owner: game.turn_owner,
+            x_mask: game.x_mask,
+            o_mask: game.o_mask,
+            turn: game.turn,
+            turn_duration: duration_from_turn(game.turn),
+            last_move: game.last_move,
+            moves: game.moves.clone(),
+            player_labels: Some((x_label, o_label)),
+            ..Self::new(Player::X, game.id.clone())
+        }
+    }
+
+    /// Whether the server drives the board, either as a player or as a spectator
+    pub fn is_network_game(&self) -> bool {
+        matches!(self.mode, GameMode::Network | GameMode::Spectating)
+    }
+
+    pub fn is_spectating(&self) -> bool {
+        self.mode == GameMode::Spectating
+    }
+
+    /// Whether the player at this device may mark a cell, always true when sharing the board
     pub fn is_primary_turn(&self) -> bool {
-        self.turn_owner == self.network_primary
+        match self.mode {
+            GameMode::HotSeat => true,
+            GameMode::Spectating => false,
+            _ => self.turn_owner == self.network_primary,
+        }
+    }
+
+    pub fn turn_owner_label(&self) -> &'static str {
+        if self.mode == GameMode::HotSeat {
+            return match self.turn_owner {
+                Player::X => "X's Turn!",
+                Player::O => "O's Turn!",
+            };
+        }
+        if self.is_primary_turn() {
+            return "Your Turn!";
+        }
+        match self.turn_owner {
+            Player::X => "X's Turn.",
+            Player::O => "O's Turn",
+        }
+    }
+
+    pub fn turn_owner_label_color(&self) -> Color {
+        if self.mode == GameMode::HotSeat {
+            return match self.turn_owner {
+                Player::X => colors::GOLD.into(),
+                Player::O => colors::DEEP_PINK.into(),
+            };
+        }
+        if self.is_primary_turn() {
+            Color::WHITE
+        } else {
+            colors::GOLD.into()
+        }
     }
 
     pub fn id(&self) -> String {
@@ -38,13 +145,47 @@ impl BoardData {
         (self.x_mask & cell_mask == 0) && (self.o_mask & cell_mask == 0)
     }
 
+    /// When the cell was marked, counting from 1
+    pub fn move_number(&self, cell_mask: u16) -> Option<usize> {
+        self.moves
+            .iter()
+            .position(|&cell| cell == cell_mask)
+            .map(|idx| idx + 1)
+    }
+
+    pub fn cell_owner(&self, cell_mask: u16) -> Option<Player> {
+        if self.x_mask & cell_mask != 0 {
+            Some(Player::X)
+        } else if self.o_mask & cell_mask != 0 {
+            Some(Player::O)
+        } else {
+            None
+        }
+    }
+
     pub fn set_result_network_primary(&mut self) {
         self.result = "You Won!".to_string()
     }
     pub fn set_result_draw(&mut self) {
         self.result = "It's a DRAW!".to_string()
     }
+    pub fn set_result_abandoned(&mut self) {
+        self.result = "Game abandoned".to_string()
+    }
+    pub fn set_result_terminated(&mut self) {
+        self.result = "Ended by an admin".to_string()
+    }
+    pub fn set_result_banned(&mut self) {
+        self.result = "You were banned".to_string()
+    }
     pub fn set_result_winner(&mut self, player: &Player) {
+        if matches!(self.mode, GameMode::HotSeat | GameMode::Spectating) {
+            self.result = match player {
+                Player::X => "X wins".to_string(),
+                Player::O => "O wins".to_string(),
+            };
+            return;
+        }
         if *player == self.network_primary {
             self.set_result_network_primary();
             return;
@@ -60,3 +201,9 @@ impl BoardData {
         &self.result
     }
 }
+
+pub fn duration_from_turn(n: u8) -> f32 {
+    let decrement_1 = [1, 2, 4].iter().filter(|&&x| x <= n).count() as f32;
+    let decrement_half = [6, 8].iter().filter(|&&x| x <= n).count() as f32;
+    5.0 - decrement_1 - decrement_half * 0.5
+}
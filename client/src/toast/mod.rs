@@ -0,0 +1,32 @@
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::network::{NetworkConnection, ReducerFailed, Stdb};
+
+pub use systems::*;
+
+// How long a toast stays up, and how many are shown at once
+const TOAST_SECS: f32 = 3.;
+const MAX_TOASTS: usize = 3;
+
+/// Column at the bottom of the window the toasts stack in, above every screen
+#[derive(Component)]
+pub struct ToastStack;
+
+#[derive(Component, Deref, DerefMut)]
+pub struct Toast(pub Timer);
+
+pub fn setup_systems(app: &mut App) {
+    app.add_systems(OnEnter(AppState::Initialization), spawn_toast_stack);
+    app.add_systems(
+        Update,
+        (
+            on_reducer_failed.run_if(on_event::<Stdb<ReducerFailed>>),
+            expire_toasts,
+        )
+            .chain()
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+}
@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+
+use crate::network::{ReducerFailed, Stdb};
+use crate::resources::FontSpaceGrotesk;
+use crate::ui::colors;
+
+use super::{MAX_TOASTS, TOAST_SECS, Toast, ToastStack};
+
+pub fn spawn_toast_stack(mut cmds: Commands) {
+    cmds.spawn((
+        ToastStack,
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(8.),
+            left: Val::Px(4.),
+            right: Val::Px(4.),
+            flex_direction: FlexDirection::ColumnReverse,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(2.),
+            ..default()
+        },
+        GlobalZIndex(11),
+    ));
+}
+
+/// Pops a toast with the server's message whenever one of this client's calls is turned down
+pub fn on_reducer_failed(
+    mut cmds: Commands,
+    mut reducer_failed_ev: EventReader<Stdb<ReducerFailed>>,
+    toast_stack_q: Query<Entity, With<ToastStack>>,
+    font: Res<FontSpaceGrotesk>,
+) -> Result {
+    let toast_stack = toast_stack_q.single()?;
+    for Stdb(failure) in reducer_failed_ev.read() {
+        cmds.entity(toast_stack).with_children(|stack| {
+            stack
+                .spawn((
+                    Toast(Timer::from_seconds(TOAST_SECS, TimerMode::Once)),
+                    Node {
+                        padding: UiRect::axes(Val::Px(6.), Val::Px(2.)),
+                        border: UiRect::all(Val::Px(1.)),
+                        ..default()
+                    },
+                    BorderRadius::all(Val::Px(4.)),
+                    BorderColor(colors::DEEP_PINK.into()),
+                    BackgroundColor(colors::DARK_VIOLET.into()),
+                ))
+                .with_child((
+                    Label,
+                    Text::new(failure.message.clone()),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 11.0,
+                        ..default()
+                    },
+                    TextColor(colors::GOLD.into()),
+                ));
+        });
+    }
+    Ok(())
+}
+
+/// Drops the toasts whose time is up, and the oldest ones past the few shown at once
+pub fn expire_toasts(
+    mut cmds: Commands,
+    mut toast_q: Query<(Entity, &mut Toast)>,
+    time: Res<Time>,
+) {
+    let mut toasts: Vec<(Entity, f32)> = Vec::new();
+    for (entity, mut toast) in &mut toast_q {
+        if toast.tick(time.delta()).finished() {
+            cmds.entity(entity).despawn();
+        } else {
+            toasts.push((entity, toast.remaining_secs()));
+        }
+    }
+    toasts.sort_by(|a, b| a.1.total_cmp(&b.1));
+    for (entity, _) in toasts.iter().rev().skip(MAX_TOASTS) {
+        cmds.entity(*entity).despawn();
+    }
+}
@@ -0,0 +1,62 @@
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::network::{
+    NetworkConnection, OnDelete, OnInsert, OnUpdate, Stdb, Tournament, TournamentEntrant,
+    TournamentFormat, TournamentPairing,
+};
+
+pub use systems::*;
+
+const MAX_ENTRANTS: u8 = 8;
+
+#[derive(Component)]
+pub struct TournamentScreen;
+
+#[derive(Component)]
+pub struct TournamentPanel;
+
+#[derive(Component)]
+pub struct BracketPanel;
+
+#[derive(Component, Clone, Copy)]
+pub enum TournamentButton {
+    Back,
+    Arena,
+    Create(TournamentFormat),
+    Join(u32),
+    Start(u32),
+}
+
+pub fn setup_systems(app: &mut App) {
+    app.add_systems(
+        OnEnter(AppState::TournamentScreen),
+        (tournament_screen, refresh_tournament_screen).chain(),
+    );
+    app.add_systems(
+        Update,
+        (
+            tournament_button_interaction,
+            refresh_tournament_screen.run_if(
+                on_event::<Stdb<OnInsert<Tournament>>>
+                    .or(on_event::<Stdb<OnUpdate<Tournament>>>)
+                    .or(on_event::<Stdb<OnInsert<TournamentEntrant>>>)
+                    .or(on_event::<Stdb<OnUpdate<TournamentEntrant>>>)
+                    .or(on_event::<Stdb<OnDelete<TournamentEntrant>>>)
+                    .or(on_event::<Stdb<OnInsert<TournamentPairing>>>)
+                    .or(on_event::<Stdb<OnUpdate<TournamentPairing>>>),
+            ),
+        )
+            .run_if(in_state(AppState::TournamentScreen))
+            .run_if(resource_exists::<NetworkConnection>),
+    );
+}
+
+pub fn format_label(format: &TournamentFormat) -> &'static str {
+    match format {
+        TournamentFormat::SingleElimination => "Cup",
+        TournamentFormat::RoundRobin => "League",
+    }
+}
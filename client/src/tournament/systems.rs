@@ -0,0 +1,331 @@
+use bevy::prelude::*;
+use spacetimedb_sdk::{DbContext, Table};
+
+use crate::AppState;
+use crate::network::{
+    CreateTournament, GameState, JoinTournament, NetworkAuth, NetworkConnection, Player,
+    StartTournament, Tournament, TournamentEntrantTableAccess, TournamentFormat,
+    TournamentPairingTableAccess, TournamentStatus, TournamentTableAccess,
+};
+use crate::resources::FontSpaceGrotesk;
+use crate::ui::{UiButtonStyle, colors, player_label, short_identity, spawn_small_button};
+
+use super::{
+    BracketPanel, MAX_ENTRANTS, TournamentButton, TournamentPanel, TournamentScreen, format_label,
+};
+
+pub fn tournament_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
+    cmds.spawn((
+        StateScoped(AppState::TournamentScreen),
+        TournamentScreen,
+        Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.),
+            padding: UiRect::top(Val::Px(6.)),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.975)),
+        ZIndex(2),
+    ))
+    .with_children(|parent| {
+        parent
+            .spawn(Node {
+                width: Val::Percent(96.),
+                justify_content: JustifyContent::SpaceBetween,
+                ..default()
+            })
+            .with_children(|top_bar| {
+                for (button, label) in [
+                    (TournamentButton::Back, "Back"),
+                    (
+                        TournamentButton::Create(TournamentFormat::SingleElimination),
+                        "+Cup",
+                    ),
+                    (
+                        TournamentButton::Create(TournamentFormat::RoundRobin),
+                        "+League",
+                    ),
+                    (TournamentButton::Arena, "Arena"),
+                ] {
+                    spawn_small_button(top_bar, &font, button, label);
+                }
+            });
+
+        parent.spawn((
+            TournamentPanel,
+            Node {
+                width: Val::Percent(96.),
+                height: Val::Percent(35.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(5.)),
+        ));
+
+        parent.spawn((
+            BracketPanel,
+            Node {
+                width: Val::Percent(96.),
+                height: Val::Percent(50.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(1.),
+                overflow: Overflow::scroll_y(),
+                border: UiRect::top(Val::Px(1.)),
+                ..default()
+            },
+            BorderColor(colors::DODGER_BLUE.with_alpha(0.5).into()),
+        ));
+    });
+}
+
+/// Rebuilds the tournament list and the bracket of the player's tournament from the cache
+pub fn refresh_tournament_screen(
+    mut cmds: Commands,
+    tournament_panel_q: Query<Entity, With<TournamentPanel>>,
+    bracket_panel_q: Query<Entity, With<BracketPanel>>,
+    connection: Res<NetworkConnection>,
+    network_auth: Res<NetworkAuth>,
+    font: Res<FontSpaceGrotesk>,
+) -> Result {
+    let tournament_panel = tournament_panel_q.single()?;
+    let bracket_panel = bracket_panel_q.single()?;
+    let me = network_auth.identity;
+
+    let mut tournaments: Vec<Tournament> = connection.db().tournament().iter().collect();
+    tournaments.sort_by_key(|t| std::cmp::Reverse(t.id));
+    let entered = |tournament_id: u32| {
+        connection
+            .db()
+            .tournament_entrant()
+            .iter()
+            .filter(|e| e.tournament_id == tournament_id)
+            .collect::<Vec<_>>()
+    };
+
+    cmds.entity(tournament_panel)
+        .despawn_related::<Children>()
+        .with_children(|list| {
+            for tournament in tournaments
+                .iter()
+                .filter(|t| t.status != TournamentStatus::Finished)
+            {
+                let entrants = entered(tournament.id);
+                let is_entrant = entrants.iter().any(|e| e.player == me);
+                list.spawn(Node {
+                    width: Val::Percent(100.),
+                    min_height: Val::Px(22.),
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Label,
+                        Text::new(format!(
+                            "#{} {} {}/{}",
+                            tournament.id,
+                            format_label(&tournament.format),
+                            entrants.len(),
+                            tournament.max_entrants
+                        )),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(colors::GOLD.into()),
+                    ));
+
+                    let action = match tournament.status {
+                        TournamentStatus::Registering if tournament.owner == me => {
+                            Some((TournamentButton::Start(tournament.id), "Start"))
+                        }
+                        TournamentStatus::Registering if !is_entrant => {
+                            Some((TournamentButton::Join(tournament.id), "Join"))
+                        }
+                        _ => None,
+                    };
+                    if let Some((button, label)) = action {
+                        spawn_small_button(row, &font, button, label);
+                    } else {
+                        let status = match tournament.status {
+                            TournamentStatus::Registering => "Entered",
+                            _ => "Playing",
+                        };
+                        row.spawn((
+                            Label,
+                            Text::new(status),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(colors::DODGER_BLUE.into()),
+                        ));
+                    }
+                });
+            }
+        });
+
+    // The bracket of the latest tournament the player entered
+    let my_tournament = tournaments
+        .iter()
+        .find(|t| entered(t.id).iter().any(|e| e.player == me));
+
+    cmds.entity(bracket_panel).despawn_related::<Children>();
+    let Some(tournament) = my_tournament else {
+        return Ok(());
+    };
+
+    let header = match (tournament.status, tournament.winner) {
+        (TournamentStatus::Registering, _) => "Waiting to start".to_string(),
+        (TournamentStatus::InProgress, _) => format!("Round {}", tournament.current_round),
+        (TournamentStatus::Finished, Some(winner)) if winner == me => "You won!".to_string(),
+        (TournamentStatus::Finished, Some(winner)) => {
+            format!("Winner: {}", short_identity(&winner))
+        }
+        (TournamentStatus::Finished, None) => "Finished".to_string(),
+    };
+
+    let mut pairings: Vec<_> = connection
+        .db()
+        .tournament_pairing()
+        .iter()
+        .filter(|p| p.tournament_id == tournament.id)
+        .collect();
+    pairings.sort_by_key(|p| (p.round, p.id));
+
+    let mut standings = entered(tournament.id);
+    standings.sort_by_key(|e| std::cmp::Reverse(e.points));
+
+    cmds.entity(bracket_panel).with_children(|bracket| {
+        bracket.spawn((
+            Label,
+            Text::new(format!(
+                "#{} {}: {header}",
+                tournament.id,
+                format_label(&tournament.format)
+            )),
+            TextFont {
+                font: font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(colors::GREEN_YELLOW.into()),
+        ));
+
+        if tournament.format == TournamentFormat::RoundRobin {
+            let table = standings
+                .iter()
+                .map(|e| format!("{} {}", player_label(&e.player, &me), e.points))
+                .collect::<Vec<_>>()
+                .join("  ");
+            bracket.spawn((
+                Label,
+                Text::new(table),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(colors::DODGER_BLUE.into()),
+            ));
+        }
+
+        for pairing in pairings {
+            let (x_color, o_color) = match pairing.result {
+                GameState::Winner(Player::X) => (colors::GREEN_YELLOW, colors::DEEP_PINK),
+                GameState::Winner(Player::O) => (colors::DEEP_PINK, colors::GREEN_YELLOW),
+                _ => (colors::GOLD, colors::GOLD),
+            };
+            let o_label = pairing
+                .o_player
+                .map_or("bye".to_string(), |o| player_label(&o, &me));
+
+            bracket
+                .spawn((
+                    Text::new(format!("R{} ", pairing.round)),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ))
+                .with_children(|line| {
+                    for (span, color) in [
+                        (player_label(&pairing.x_player, &me), x_color),
+                        (" v ".to_string(), colors::DODGER_BLUE),
+                        (o_label, o_color),
+                    ] {
+                        line.spawn((
+                            TextSpan::new(span),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(color.into()),
+                        ));
+                    }
+                });
+        }
+    });
+    Ok(())
+}
+
+#[allow(clippy::type_complexity)]
+pub fn tournament_button_interaction(
+    mut interaction_query: Query<
+        (
+            &TournamentButton,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut TextColor>,
+    mut game_state: ResMut<NextState<AppState>>,
+    conn: Res<NetworkConnection>,
+) {
+    for (tournament_button, button_style, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = button_style.text_color.into();
+                match *tournament_button {
+                    TournamentButton::Back => game_state.set(AppState::HomeScreen),
+                    TournamentButton::Arena => game_state.set(AppState::ArenaScreen),
+                    TournamentButton::Create(format) => {
+                        conn.reducers
+                            .create_tournament(format, MAX_ENTRANTS)
+                            .unwrap();
+                    }
+                    TournamentButton::Join(id) => conn.reducers.join_tournament(id).unwrap(),
+                    TournamentButton::Start(id) => conn.reducers.start_tournament(id).unwrap(),
+                }
+            }
+            Interaction::Hovered => {
+                *color = button_style.text_color.into();
+                *text_color = button_style.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = button_style.color.into();
+                *text_color = button_style.text_color.into();
+                *border_color = button_style.border_color.into();
+            }
+        }
+    }
+}
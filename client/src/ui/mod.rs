@@ -3,6 +3,8 @@ mod systems;
 
 use bevy::prelude::*;
 
+use crate::network::{BotDifficulty, SidePreference};
+
 pub use systems::*;
 
 #[derive(Component)]
@@ -20,6 +22,113 @@ pub struct JoinGameButton(pub u32);
 #[derive(Component)]
 pub struct NewGameButton;
 
+#[derive(Component)]
+pub struct TournamentsButton;
+
+#[derive(Component)]
+pub struct LiveGamesLinkButton;
+
+#[derive(Component)]
+pub struct FriendsLinkButton;
+
+/// Server-wide message of the day, across the top of the home screen
+#[derive(Component)]
+pub struct MotdBanner;
+
+/// Whether a created room plays for rating, cycled on press and sent with `create_room`
+#[derive(Component, Clone, Copy)]
+pub struct RatedToggleButton(pub bool);
+
+impl RatedToggleButton {
+    pub fn label(&self) -> &'static str {
+        if self.0 { "Rtd" } else { "Cas" }
+    }
+}
+
+#[derive(Component)]
+pub struct LobbyFilterChip;
+
+#[derive(Component)]
+pub struct LobbySortChip;
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum LobbySort {
+    #[default]
+    Newest,
+    ClosestRating,
+    Variant,
+}
+
+/// How the lobby list is narrowed down and ordered, kept while browsing other screens
+#[derive(Resource, Default)]
+pub struct LobbyFilter {
+    /// `None` lists rated and casual rooms alike
+    pub rated: Option<bool>,
+    pub sort: LobbySort,
+}
+
+impl LobbyFilter {
+    pub fn next_filter(&mut self) {
+        self.rated = match self.rated {
+            None => Some(true),
+            Some(true) => Some(false),
+            Some(false) => None,
+        };
+    }
+
+    pub fn next_sort(&mut self) {
+        self.sort = match self.sort {
+            LobbySort::Newest => LobbySort::ClosestRating,
+            LobbySort::ClosestRating => LobbySort::Variant,
+            LobbySort::Variant => LobbySort::Newest,
+        };
+    }
+
+    pub fn filter_label(&self) -> &'static str {
+        match self.rated {
+            None => "All",
+            Some(true) => "Rated",
+            Some(false) => "Casual",
+        }
+    }
+
+    pub fn sort_label(&self) -> &'static str {
+        match self.sort {
+            LobbySort::Newest => "New",
+            LobbySort::ClosestRating => "Elo",
+            LobbySort::Variant => "Var",
+        }
+    }
+}
+
+/// Side the room owner wants to play, cycled on press and sent with `create_room`
+#[derive(Component, Clone, Copy)]
+pub struct SideToggleButton(pub SidePreference);
+
+impl SideToggleButton {
+    pub fn next(&mut self) {
+        self.0 = match self.0 {
+            SidePreference::Random => SidePreference::X,
+            SidePreference::X => SidePreference::O,
+            SidePreference::O => SidePreference::Random,
+        };
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self.0 {
+            SidePreference::X => "X",
+            SidePreference::O => "O",
+            SidePreference::Random => "?",
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct PlayBotButton(pub BotDifficulty);
+
+#[derive(Component)]
+pub struct HotSeatButton;
+
 #[derive(Component, Clone, Copy)]
 pub struct UiButtonStyle {
     pub color: Color,
@@ -40,10 +149,28 @@ pub struct TurnTimeCounter;
 pub struct LeaveGameButton;
 
 const CELL_SIZE: f32 = 60.;
+/// Width of the window set in main.rs, screens that sit next to the chat keep to it
+pub const SCREEN_WIDTH: f32 = 188.;
 
 #[derive(Component)]
 pub struct CellMarked;
 
+/// Mark drawn as soon as the cell is pressed, until the server confirms or rejects the move
+#[derive(Component)]
+pub struct PendingMark;
+
+/// Shakes a cell whose move was turned down
+#[derive(Component, Deref, DerefMut)]
+pub struct CellShake(pub Timer);
+
+const SHAKE_SECS: f32 = 0.4;
+
+/// Counts down while the winning line is on show, the game-over screen comes after
+#[derive(Resource, Deref, DerefMut)]
+pub struct GameOverDelay(pub Timer);
+
+const WINNING_LINE_SECS: f32 = 1.2;
+
 #[derive(Component, Deref)]
 pub struct GridCell(pub u16);
 
@@ -53,5 +180,18 @@ pub struct Grid;
 #[derive(Component)]
 pub struct GameOverScreen;
 
+#[derive(Component)]
+pub struct GoBackButton;
+
 #[derive(Component)]
 pub struct LobbyRoomScreen;
+
+#[derive(Component)]
+pub struct LeaveRoomButton;
+
+#[derive(Component)]
+pub struct KeepAliveButton;
+
+/// Time left before the server closes the room
+#[derive(Component)]
+pub struct RoomExpiryCountdown;
@@ -1,32 +1,65 @@
 use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
-use spacetimedb_sdk::{DbContext, Table};
+use spacetimedb_sdk::{DbContext, Identity, Table, Timestamp};
 
 use crate::AppState;
 use crate::network::{
-    CreateRoom, JoinGame, LeaveGame, LeaveRoom, LobbyRoomTableAccess, MarkCell, NetworkConnection,
+    BlockTableAccess, BotDifficulty, CreateRoom, DEFAULT_RATING, JoinGame, JoinRequestTableAccess,
+    KeepRoomAlive, LeaveGame, LeaveRoom, LobbyRoom, LobbyRoomTableAccess, MarkCell, Motd,
+    MotdTableAccess, NetworkAuth, NetworkConnection, OnDelete, OnInsert, OnUpdate, PlayVsBot,
+    Player, PlayerRatingTableAccess, SidePreference, Stdb, StopWatching,
 };
-use crate::resources::{BoardData, BoardSystems, FontSpaceGrotesk};
+use crate::offline::{self, LocalMove};
+use crate::resources::{BoardData, BoardSystems, FontSpaceGrotesk, GameMode};
 
 use super::{
-    CELL_SIZE, CellMarked, GameOverScreen, Grid, GridCell, JoinGameButton, LeaveGameButton,
-    LobbyRoomId, LobbyRoomScreen, TopBar, TurnOwnerLabel, TurnTimeCounter,
+    CELL_SIZE, CellMarked, CellShake, GameOverDelay, GameOverScreen, GoBackButton, Grid, GridCell,
+    JoinGameButton, KeepAliveButton, LeaveGameButton, LeaveRoomButton, LobbyFilter,
+    LobbyFilterChip, LobbyRoomId, LobbyRoomScreen, LobbySort, LobbySortChip, PendingMark,
+    RatedToggleButton, RoomExpiryCountdown, SCREEN_WIDTH, SHAKE_SECS, TopBar, TurnOwnerLabel,
+    TurnTimeCounter, WINNING_LINE_SECS,
+};
+use super::{
+    FriendsLinkButton, HomeScreen, HotSeatButton, LiveGamesLinkButton, LobbyPanel, MotdBanner,
+    NewGameButton, PlayBotButton, SideToggleButton, TournamentsButton, UiButtonStyle, colors,
 };
-use super::{HomeScreen, LobbyPanel, NewGameButton, UiButtonStyle, colors};
 
 // TODO: Cleanup this module
 
 pub fn setup_systems(app: &mut App) {
+    app.init_resource::<LobbyFilter>();
+
     // Main menu
     app.add_systems(
         OnEnter(AppState::HomeScreen),
-        (home_screen, populate_lobby_from_cache).chain(),
+        (
+            home_screen,
+            (populate_lobby_from_cache, refresh_motd_banner)
+                .run_if(resource_exists::<NetworkConnection>),
+        )
+            .chain(),
     );
     app.add_systems(
         Update,
         (
-            new_game_button_interaction,
-            join_game_button_interaction,
+            (
+                new_game_button_interaction,
+                join_game_button_interaction,
+                tournaments_button_interaction,
+                live_games_link_button_interaction,
+                friends_link_button_interaction,
+                rated_toggle_button_interaction,
+                lobby_chip_interaction,
+                refresh_motd_banner.run_if(
+                    on_event::<Stdb<OnInsert<Motd>>>
+                        .or(on_event::<Stdb<OnUpdate<Motd>>>)
+                        .or(on_event::<Stdb<OnDelete<Motd>>>),
+                ),
+            )
+                .run_if(resource_exists::<NetworkConnection>),
+            side_toggle_button_interaction,
+            play_bot_button_interaction,
+            hot_seat_button_interaction,
             update_lobby_scroll_position,
         )
             .run_if(in_state(AppState::HomeScreen)),
@@ -36,7 +69,12 @@ pub fn setup_systems(app: &mut App) {
     app.add_systems(OnEnter(AppState::LobbyScreen), lobby_room_screen);
     app.add_systems(
         Update,
-        (lobby_screen_leave_interaction,).run_if(in_state(AppState::LobbyScreen)),
+        (
+            lobby_screen_leave_interaction,
+            keep_alive_button_interaction,
+            room_expiry_countdown_update,
+        )
+            .run_if(in_state(AppState::LobbyScreen)),
     );
 
     // Game initialization step
@@ -45,13 +83,25 @@ pub fn setup_systems(app: &mut App) {
     // Game in Progress
     app.add_systems(
         Update,
-        (leave_game_button_interaction, grid_cell_interaction)
+        (
+            leave_game_button_interaction,
+            // Spectators only get to watch
+            grid_cell_interaction.run_if(
+                (|board: Res<BoardData>| !board.is_spectating())
+                    .and(not(resource_exists::<GameOverDelay>)),
+            ),
+            shake_grid_cells,
+            highlight_winning_line.run_if(resource_added::<GameOverDelay>),
+            game_over_delay_update.run_if(resource_exists::<GameOverDelay>),
+        )
             .run_if(in_state(AppState::GameInProgress)),
     );
     app.add_systems(
         FixedUpdate,
-        (turn_expiration_time_update).run_if(in_state(AppState::GameInProgress)),
+        (turn_expiration_time_update)
+            .run_if(in_state(AppState::GameInProgress).and(not(resource_exists::<GameOverDelay>))),
     );
+    app.add_systems(OnExit(AppState::GameInProgress), clear_game_over_delay);
 
     // Wrap up the match
     app.add_systems(OnEnter(AppState::GameOverScreen), game_over_screen);
@@ -61,7 +111,13 @@ pub fn setup_systems(app: &mut App) {
     );
 }
 
-pub fn home_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
+pub fn home_screen(
+    mut cmds: Commands,
+    font: Res<FontSpaceGrotesk>,
+    lobby_filter: Res<LobbyFilter>,
+    maybe_connection: Option<Res<NetworkConnection>>,
+) {
+    let online = maybe_connection.is_some();
     cmds.spawn((
         StateScoped(AppState::HomeScreen),
         HomeScreen,
@@ -78,6 +134,24 @@ pub fn home_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
         ZIndex(1),
     ))
     .with_children(|parent| {
+        parent.spawn((
+            MotdBanner,
+            Label,
+            Text::new(""),
+            TextFont {
+                font: font.clone(),
+                font_size: 9.0,
+                ..default()
+            },
+            TextColor(colors::GREEN_YELLOW.into()),
+            TextLayout::new_with_justify(JustifyText::Center),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(1.),
+                width: Val::Percent(96.),
+                ..default()
+            },
+        ));
         parent.spawn((
             Label,
             Text::new("Tic-Tac-Toe"),
@@ -105,37 +179,247 @@ pub fn home_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
             border_color: colors::DODGER_BLUE.into(),
             text_color: colors::DARK_VIOLET.into(),
         };
+        if online {
+            parent
+                .spawn((
+                    NewGameButton,
+                    Button,
+                    ui_button_style,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(50.),
+                        right: Val::Px(60.),
+                        height: Val::Px(30.0),
+                        min_width: Val::Px(70.),
+                        border: UiRect::all(Val::Px(1.0)),
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        grid_column: GridPlacement::start(2),
+                        ..default()
+                    },
+                    ZIndex(3),
+                    BorderRadius::all(Val::Px(5.0)),
+                    BorderColor(ui_button_style.border_color),
+                    BackgroundColor(ui_button_style.color),
+                    BoxShadow::new(
+                        colors::DODGER_BLUE.with_alpha(0.5).into(),
+                        Val::Px(0.),
+                        Val::Px(-2.),
+                        Val::Percent(10.),
+                        Val::Px(5.0),
+                    ),
+                ))
+                .with_child((
+                    Text::new("Create"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(ui_button_style.text_color),
+                ));
+
+            parent
+                .spawn((
+                    TournamentsButton,
+                    Button,
+                    ui_button_style,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(50.),
+                        left: Val::Px(8.),
+                        height: Val::Px(14.0),
+                        width: Val::Px(44.),
+                        border: UiRect::all(Val::Px(1.0)),
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    ZIndex(3),
+                    BorderRadius::all(Val::Px(4.0)),
+                    BorderColor(ui_button_style.border_color),
+                    BackgroundColor(ui_button_style.color),
+                ))
+                .with_child((
+                    Text::new("Cups"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 11.0,
+                        ..default()
+                    },
+                    TextColor(ui_button_style.text_color),
+                ));
+
+            parent
+                .spawn((
+                    FriendsLinkButton,
+                    Button,
+                    ui_button_style,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(66.),
+                        left: Val::Px(8.),
+                        height: Val::Px(14.0),
+                        width: Val::Px(44.),
+                        border: UiRect::all(Val::Px(1.0)),
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    ZIndex(3),
+                    BorderRadius::all(Val::Px(4.0)),
+                    BorderColor(ui_button_style.border_color),
+                    BackgroundColor(ui_button_style.color),
+                ))
+                .with_child((
+                    Text::new("Friends"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 11.0,
+                        ..default()
+                    },
+                    TextColor(ui_button_style.text_color),
+                ));
+
+            // Ongoing games anyone can watch, right above the lobby list
+            parent
+                .spawn((
+                    LiveGamesLinkButton,
+                    Button,
+                    ui_button_style,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(82.),
+                        left: Val::Px(8.),
+                        height: Val::Px(16.0),
+                        width: Val::Px(44.),
+                        border: UiRect::all(Val::Px(1.0)),
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    ZIndex(3),
+                    BorderRadius::all(Val::Px(4.0)),
+                    BorderColor(ui_button_style.border_color),
+                    BackgroundColor(ui_button_style.color),
+                ))
+                .with_child((
+                    Text::new("Live"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(ui_button_style.text_color),
+                ));
+
+            // Narrow down and order the lobby list
+            let chip_style = UiButtonStyle {
+                color: colors::GOLD.into(),
+                border_color: colors::DEEP_PINK.into(),
+                text_color: colors::DARK_VIOLET.into(),
+            };
+            parent
+                .spawn(Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(82.),
+                    left: Val::Px(56.),
+                    column_gap: Val::Px(4.),
+                    ..default()
+                })
+                .with_children(|chips| {
+                    let labels = [lobby_filter.filter_label(), lobby_filter.sort_label()];
+                    for (idx, label) in labels.into_iter().enumerate() {
+                        let mut chip = chips.spawn((
+                            Button,
+                            chip_style,
+                            Node {
+                                height: Val::Px(16.0),
+                                min_width: Val::Px(36.),
+                                border: UiRect::all(Val::Px(1.0)),
+                                align_items: AlignItems::Center,
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                            ZIndex(3),
+                            BorderRadius::all(Val::Px(8.0)),
+                            BorderColor(chip_style.border_color),
+                            BackgroundColor(chip_style.color),
+                        ));
+                        if idx == 0 {
+                            chip.insert(LobbyFilterChip);
+                        } else {
+                            chip.insert(LobbySortChip);
+                        }
+                        chip.with_child((
+                            Text::new(label),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 10.0,
+                                ..default()
+                            },
+                            TextColor(chip_style.text_color),
+                        ));
+                    }
+                });
+
+            let rated_toggle = RatedToggleButton(true);
+            parent
+                .spawn((
+                    rated_toggle,
+                    Button,
+                    ui_button_style,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(82.),
+                        right: Val::Px(20.),
+                        height: Val::Px(16.0),
+                        width: Val::Px(30.),
+                        border: UiRect::all(Val::Px(1.0)),
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    ZIndex(3),
+                    BorderRadius::all(Val::Px(4.0)),
+                    BorderColor(ui_button_style.border_color),
+                    BackgroundColor(ui_button_style.color),
+                ))
+                .with_child((
+                    Text::new(rated_toggle.label()),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 10.0,
+                        ..default()
+                    },
+                    TextColor(ui_button_style.text_color),
+                ));
+        }
+
+        let side_toggle = SideToggleButton(SidePreference::Random);
         parent
             .spawn((
-                NewGameButton,
+                side_toggle,
                 Button,
                 ui_button_style,
                 Node {
                     position_type: PositionType::Absolute,
                     top: Val::Px(50.),
-                    right: Val::Px(60.),
+                    right: Val::Px(20.),
                     height: Val::Px(30.0),
-                    min_width: Val::Px(70.),
+                    width: Val::Px(30.),
                     border: UiRect::all(Val::Px(1.0)),
                     align_items: AlignItems::Center,
                     justify_content: JustifyContent::Center,
-                    grid_column: GridPlacement::start(2),
                     ..default()
                 },
                 ZIndex(3),
                 BorderRadius::all(Val::Px(5.0)),
                 BorderColor(ui_button_style.border_color),
                 BackgroundColor(ui_button_style.color),
-                BoxShadow::new(
-                    colors::DODGER_BLUE.with_alpha(0.5).into(),
-                    Val::Px(0.),
-                    Val::Px(-2.),
-                    Val::Percent(10.),
-                    Val::Px(5.0),
-                ),
             ))
             .with_child((
-                Text::new("Create"),
+                Text::new(side_toggle.label()),
                 TextFont {
                     font: font.clone(),
                     font_size: 18.0,
@@ -144,35 +428,492 @@ pub fn home_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
                 TextColor(ui_button_style.text_color),
             ));
 
-        parent.spawn((
-            LobbyPanel,
-            Node {
-                width: Val::Percent(96.),
-                height: Val::Percent(94.),
-                // grid_row: GridPlacement::start_end(4, -1),
-                grid_row: GridPlacement::start_span(4, 6),
-                display: Display::Grid,
-                row_gap: Val::Px(2.),
-                overflow: Overflow::scroll_y(),
-                overflow_clip_margin: OverflowClipMargin::padding_box(),
-                grid_auto_rows: vec![GridTrack::px(30.)],
-                grid_auto_flow: GridAutoFlow::Row,
-                align_items: AlignItems::Center,
-                justify_items: JustifyItems::Center,
-                margin: UiRect {
-                    top: Val::Px(25.),
-                    ..default()
-                },
-                ..default()
-            },
-            ZIndex(2),
-            BorderRadius::all(Val::Px(5.)),
-        ));
-    });
+        if !online {
+            // Without a server there is no lobby, only games on this device
+            parent.spawn((
+                Label,
+                Text::new("Offline"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(colors::DEEP_PINK.into()),
+                Node {
+                    grid_row: GridPlacement::start_span(4, 6),
+                    ..default()
+                },
+            ));
+        } else {
+            parent.spawn((
+                LobbyPanel,
+                Node {
+                    width: Val::Percent(96.),
+                    height: Val::Percent(94.),
+                    // grid_row: GridPlacement::start_end(4, -1),
+                    grid_row: GridPlacement::start_span(4, 6),
+                    display: Display::Grid,
+                    row_gap: Val::Px(2.),
+                    overflow: Overflow::scroll_y(),
+                    overflow_clip_margin: OverflowClipMargin::padding_box(),
+                    grid_auto_rows: vec![GridTrack::px(30.)],
+                    grid_auto_flow: GridAutoFlow::Row,
+                    align_items: AlignItems::Center,
+                    justify_items: JustifyItems::Center,
+                    margin: UiRect {
+                        top: Val::Px(25.),
+                        ..default()
+                    },
+                    ..default()
+                },
+                ZIndex(2),
+                BorderRadius::all(Val::Px(5.)),
+            ));
+        }
+
+        parent
+            .spawn(Node {
+                width: Val::Percent(96.),
+                grid_row: GridPlacement::start(10),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..default()
+            })
+            .with_children(|bot_bar| {
+                bot_bar.spawn((
+                    Label,
+                    Text::new("Bot:"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(colors::GOLD.into()),
+                ));
+
+                let bot_button_style = UiButtonStyle {
+                    color: colors::GOLD.into(),
+                    border_color: colors::DEEP_PINK.into(),
+                    text_color: colors::DARK_VIOLET.into(),
+                };
+                for (difficulty, label) in [
+                    (BotDifficulty::Easy, "Easy"),
+                    (BotDifficulty::Medium, "Mid"),
+                    (BotDifficulty::Hard, "Hard"),
+                ] {
+                    bot_bar
+                        .spawn((
+                            PlayBotButton(difficulty),
+                            Button,
+                            bot_button_style,
+                            Node {
+                                height: Val::Px(20.0),
+                                min_width: Val::Px(38.),
+                                border: UiRect::all(Val::Px(1.0)),
+                                align_items: AlignItems::Center,
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                            BorderRadius::all(Val::Px(4.0)),
+                            BorderColor(bot_button_style.border_color),
+                            BackgroundColor(bot_button_style.color),
+                        ))
+                        .with_child((
+                            Text::new(label),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(bot_button_style.text_color),
+                        ));
+                }
+
+                // Two players sharing this device
+                bot_bar
+                    .spawn((
+                        HotSeatButton,
+                        Button,
+                        ui_button_style,
+                        Node {
+                            height: Val::Px(20.0),
+                            min_width: Val::Px(38.),
+                            border: UiRect::all(Val::Px(1.0)),
+                            align_items: AlignItems::Center,
+                            justify_content: JustifyContent::Center,
+                            ..default()
+                        },
+                        BorderRadius::all(Val::Px(4.0)),
+                        BorderColor(ui_button_style.border_color),
+                        BackgroundColor(ui_button_style.color),
+                    ))
+                    .with_child((
+                        Text::new("2P"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(ui_button_style.text_color),
+                    ));
+            });
+    });
+}
+
+#[allow(clippy::type_complexity)]
+pub fn new_game_button_interaction(
+    mut interaction_query: Query<
+        (
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        (Changed<Interaction>, With<NewGameButton>),
+    >,
+    mut text_query: Query<&mut TextColor>,
+    side_toggle_q: Query<&SideToggleButton>,
+    rated_toggle_q: Query<&RatedToggleButton>,
+    conn: Res<NetworkConnection>,
+) {
+    for (start_button, interaction, mut color, mut border_color, children) in &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = start_button.text_color.into();
+
+                let owner_side = side_toggle_q
+                    .single()
+                    .map_or(SidePreference::Random, |side| side.0);
+                let rated = rated_toggle_q.single().map_or(true, |rated| rated.0);
+                conn.reducers.create_room(owner_side, rated).unwrap();
+            }
+            Interaction::Hovered => {
+                *color = start_button.text_color.into();
+                *text_color = start_button.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = start_button.color.into();
+                *text_color = start_button.text_color.into();
+                *border_color = start_button.border_color.into();
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn tournaments_button_interaction(
+    mut interaction_query: Query<
+        (
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        (Changed<Interaction>, With<TournamentsButton>),
+    >,
+    mut text_query: Query<&mut TextColor>,
+    mut game_state: ResMut<NextState<AppState>>,
+) {
+    for (tournaments_button, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = tournaments_button.text_color.into();
+                game_state.set(AppState::TournamentScreen);
+            }
+            Interaction::Hovered => {
+                *color = tournaments_button.text_color.into();
+                *text_color = tournaments_button.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = tournaments_button.color.into();
+                *text_color = tournaments_button.text_color.into();
+                *border_color = tournaments_button.border_color.into();
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn live_games_link_button_interaction(
+    mut interaction_query: Query<
+        (
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        (Changed<Interaction>, With<LiveGamesLinkButton>),
+    >,
+    mut text_query: Query<&mut TextColor>,
+    mut game_state: ResMut<NextState<AppState>>,
+) {
+    for (live_button, interaction, mut color, mut border_color, children) in &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = live_button.text_color.into();
+                game_state.set(AppState::LiveGamesScreen);
+            }
+            Interaction::Hovered => {
+                *color = live_button.text_color.into();
+                *text_color = live_button.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = live_button.color.into();
+                *text_color = live_button.text_color.into();
+                *border_color = live_button.border_color.into();
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn friends_link_button_interaction(
+    mut interaction_query: Query<
+        (
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        (Changed<Interaction>, With<FriendsLinkButton>),
+    >,
+    mut text_query: Query<&mut TextColor>,
+    mut game_state: ResMut<NextState<AppState>>,
+) {
+    for (friends_button, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = friends_button.text_color.into();
+                game_state.set(AppState::FriendsScreen);
+            }
+            Interaction::Hovered => {
+                *color = friends_button.text_color.into();
+                *text_color = friends_button.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = friends_button.color.into();
+                *text_color = friends_button.text_color.into();
+                *border_color = friends_button.border_color.into();
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn side_toggle_button_interaction(
+    mut interaction_query: Query<
+        (
+            &mut SideToggleButton,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<(&mut Text, &mut TextColor)>,
+) {
+    for (mut side_toggle, toggle_button, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let (mut text, mut text_color) = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = toggle_button.text_color.into();
+
+                side_toggle.next();
+                *text = side_toggle.label().into();
+            }
+            Interaction::Hovered => {
+                *color = toggle_button.text_color.into();
+                *text_color = toggle_button.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = toggle_button.color.into();
+                *text_color = toggle_button.text_color.into();
+                *border_color = toggle_button.border_color.into();
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn rated_toggle_button_interaction(
+    mut interaction_query: Query<
+        (
+            &mut RatedToggleButton,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<(&mut Text, &mut TextColor)>,
+) -> Result {
+    for (mut rated_toggle, toggle_button, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let (mut text, mut text_color) = text_query.get_mut(children[0])?;
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = toggle_button.text_color.into();
+
+                rated_toggle.0 = !rated_toggle.0;
+                *text = rated_toggle.label().into();
+            }
+            Interaction::Hovered => {
+                *color = toggle_button.text_color.into();
+                *text_color = toggle_button.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = toggle_button.color.into();
+                *text_color = toggle_button.text_color.into();
+                *border_color = toggle_button.border_color.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Cycles the lobby filter or sort order and rebuilds the list
+#[allow(clippy::type_complexity)]
+pub fn lobby_chip_interaction(
+    mut cmds: Commands,
+    mut interaction_query: Query<
+        (
+            Has<LobbyFilterChip>,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        (
+            Changed<Interaction>,
+            Or<(With<LobbyFilterChip>, With<LobbySortChip>)>,
+        ),
+    >,
+    mut text_query: Query<(&mut Text, &mut TextColor)>,
+    mut lobby_filter: ResMut<LobbyFilter>,
+) -> Result {
+    for (is_filter, chip_style, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let (mut text, mut text_color) = text_query.get_mut(children[0])?;
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = chip_style.text_color.into();
+
+                if is_filter {
+                    lobby_filter.next_filter();
+                    *text = lobby_filter.filter_label().into();
+                } else {
+                    lobby_filter.next_sort();
+                    *text = lobby_filter.sort_label().into();
+                }
+                cmds.run_system_cached(populate_lobby_from_cache);
+            }
+            Interaction::Hovered => {
+                *color = chip_style.text_color.into();
+                *text_color = chip_style.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = chip_style.color.into();
+                *text_color = chip_style.text_color.into();
+                *border_color = chip_style.border_color.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+#[allow(clippy::type_complexity)]
+pub fn play_bot_button_interaction(
+    mut interaction_query: Query<
+        (
+            &PlayBotButton,
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        Changed<Interaction>,
+    >,
+    mut cmds: Commands,
+    mut text_query: Query<&mut TextColor>,
+    mut game_state: ResMut<NextState<AppState>>,
+    side_toggle_q: Query<&SideToggleButton>,
+    maybe_conn: Option<Res<NetworkConnection>>,
+) {
+    for (play_bot, bot_button, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = bot_button.text_color.into();
+
+                let side = side_toggle_q
+                    .single()
+                    .map_or(SidePreference::Random, |side| side.0);
+                if let Some(conn) = &maybe_conn {
+                    conn.reducers.play_vs_bot(play_bot.0, side).unwrap();
+                } else {
+                    // Offline, the computer plays on this device
+                    let player = offline::resolve_local_side(side);
+                    cmds.insert_resource(BoardData::vs_computer(player, play_bot.0));
+                    game_state.set(AppState::GameSetup);
+                }
+            }
+            Interaction::Hovered => {
+                *color = bot_button.text_color.into();
+                *text_color = bot_button.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = bot_button.color.into();
+                *text_color = bot_button.text_color.into();
+                *border_color = bot_button.border_color.into();
+            }
+        }
+    }
 }
 
 #[allow(clippy::type_complexity)]
-pub fn new_game_button_interaction(
+pub fn hot_seat_button_interaction(
     mut interaction_query: Query<
         (
             &UiButtonStyle,
@@ -181,30 +922,33 @@ pub fn new_game_button_interaction(
             &mut BorderColor,
             &Children,
         ),
-        (Changed<Interaction>, With<NewGameButton>),
+        (Changed<Interaction>, With<HotSeatButton>),
     >,
+    mut cmds: Commands,
     mut text_query: Query<&mut TextColor>,
-    conn: Res<NetworkConnection>,
+    mut game_state: ResMut<NextState<AppState>>,
 ) {
-    for (start_button, interaction, mut color, mut border_color, children) in &mut interaction_query
+    for (hot_seat_button, interaction, mut color, mut border_color, children) in
+        &mut interaction_query
     {
         let mut text_color = text_query.get_mut(children[0]).unwrap();
         match *interaction {
             Interaction::Pressed => {
                 *color = Color::WHITE.into();
-                *border_color = start_button.text_color.into();
+                *border_color = hot_seat_button.text_color.into();
 
-                conn.reducers.create_room().unwrap();
+                cmds.insert_resource(BoardData::hot_seat());
+                game_state.set(AppState::GameSetup);
             }
             Interaction::Hovered => {
-                *color = start_button.text_color.into();
-                *text_color = start_button.color.into();
+                *color = hot_seat_button.text_color.into();
+                *text_color = hot_seat_button.color.into();
                 *border_color = Color::WHITE.into();
             }
             Interaction::None => {
-                *color = start_button.color.into();
-                *text_color = start_button.text_color.into();
-                *border_color = start_button.border_color.into();
+                *color = hot_seat_button.color.into();
+                *text_color = hot_seat_button.text_color.into();
+                *border_color = hot_seat_button.border_color.into();
             }
         }
     }
@@ -235,6 +979,7 @@ pub fn join_game_button_interaction(
                 *color = Color::WHITE.into();
                 *border_color = start_button.text_color.into();
 
+                // Asks the room's owner to let this client in
                 conn.reducers.join_game(join_game.0).unwrap();
             }
             Interaction::Hovered => {
@@ -276,7 +1021,7 @@ pub fn initialize_grid_board(
         StateScoped(AppState::GameInProgress),
         TopBar,
         Node {
-            width: Val::Percent(100.),
+            width: Val::Px(SCREEN_WIDTH),
             height: Val::Px(CELL_SIZE),
             align_items: AlignItems::Center,
             justify_content: JustifyContent::SpaceEvenly,
@@ -337,13 +1082,13 @@ pub fn initialize_grid_board(
                 left_side.spawn((
                     TurnOwnerLabel,
                     Label,
-                    Text::new("X's Turn."),
+                    Text::new(game_board.turn_owner_label()),
                     TextFont {
                         font: font.clone(),
                         font_size: 16.0,
                         ..default()
                     },
-                    TextColor(colors::GOLD.into()),
+                    TextColor(game_board.turn_owner_label_color()),
                 ));
             });
 
@@ -356,16 +1101,34 @@ pub fn initialize_grid_board(
                 ..default()
             })
             .with_children(|right_side| {
-                right_side.spawn((
-                    Label,
-                    Text::new("Time Left:"),
-                    TextFont {
-                        font: font.clone(),
-                        font_size: 16.0,
-                        ..default()
-                    },
-                    TextColor(colors::GOLD.into()),
-                ));
+                if let Some((x_label, o_label)) = &game_board.player_labels {
+                    for (label, color) in [
+                        (format!("X {x_label}"), colors::GOLD),
+                        (format!("O {o_label}"), colors::DEEP_PINK),
+                    ] {
+                        right_side.spawn((
+                            Label,
+                            Text::new(label),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(color.into()),
+                        ));
+                    }
+                } else {
+                    right_side.spawn((
+                        Label,
+                        Text::new("Time Left:"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(colors::GOLD.into()),
+                    ));
+                }
                 right_side.spawn((
                     TurnTimeCounter,
                     Label,
@@ -407,8 +1170,9 @@ pub fn initialize_grid_board(
     ))
     .with_children(|grid| {
         for idx in 1u16..=9 {
-            grid.spawn((
-                GridCell(1 << (idx - 1)), // Stores each cell on a different bit
+            let cell_mask = 1 << (idx - 1); // Stores each cell on a different bit
+            let mut cell = grid.spawn((
+                GridCell(cell_mask),
                 Button,
                 Node {
                     width: Val::Px(CELL_SIZE),
@@ -422,6 +1186,27 @@ pub fn initialize_grid_board(
                 BackgroundColor(colors::DODGER_BLUE.into()),
                 BorderRadius::all(Val::Px(5.)),
             ));
+
+            // Spectators can join a game halfway through
+            if let Some(player) = game_board.cell_owner(cell_mask) {
+                let (letter, bg_color, text_color) = mark_style(player);
+                cell.insert((CellMarked, BackgroundColor(bg_color.into())))
+                    .with_child((
+                        Text::new(letter),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 40.0,
+                            ..default()
+                        },
+                        TextColor(text_color.into()),
+                    ));
+                if let Some(number) = game_board
+                    .move_number(cell_mask)
+                    .filter(|_| game_board.is_spectating())
+                {
+                    cell.with_child(move_number_label(number, player, &font));
+                }
+            }
         }
     });
 
@@ -429,21 +1214,30 @@ pub fn initialize_grid_board(
 }
 
 pub fn turn_expiration_time_update(
-    mut turn_time_label_q: Query<&mut Text, With<TurnTimeCounter>>,
+    mut turn_time_label_q: Query<&mut Text, (With<TurnTimeCounter>, Without<TurnOwnerLabel>)>,
+    mut turn_owner_label_q: Query<(&mut Text, &mut TextColor), With<TurnOwnerLabel>>,
     mut game_board: ResMut<BoardData>,
     time: Res<Time>,
 ) -> Result {
     let mut turn_time_label = turn_time_label_q.single_mut()?;
 
     game_board.turn_duration -= time.delta_secs();
+    // Network games are expired by the server, local ones have to expire themselves
+    if !game_board.is_network_game() && game_board.turn_duration <= 0. {
+        offline::expire_local_turn(&mut game_board);
+        let (mut turn_owner_label, mut text_color) = turn_owner_label_q.single_mut()?;
+        refresh_turn_owner_label(&mut turn_owner_label, &mut text_color, &game_board);
+    }
     *turn_time_label = format!("{:.2}s", game_board.turn_duration).into();
     Ok(())
 }
 
 #[allow(clippy::type_complexity)]
 pub fn grid_cell_interaction(
+    mut cmds: Commands,
     mut interaction_query: Query<
         (
+            Entity,
             &GridCell,
             &Interaction,
             &mut BackgroundColor,
@@ -451,14 +1245,35 @@ pub fn grid_cell_interaction(
         ),
         (Changed<Interaction>, With<Button>, Without<CellMarked>),
     >,
-    board: Res<BoardData>,
-    conn: Res<NetworkConnection>,
+    mut local_move_writer: EventWriter<LocalMove>,
+    mut board: ResMut<BoardData>,
+    maybe_conn: Option<Res<NetworkConnection>>,
+    font: Res<FontSpaceGrotesk>,
 ) {
-    for (cell, interaction, mut color, mut border_color) in &mut interaction_query {
+    for (entity, cell, interaction, mut color, mut border_color) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
-                if board.is_primary_turn() && board.cell_is_free(**cell) {
-                    conn.reducers.mark_cell(board.id(), **cell).unwrap();
+                // One move in flight at a time
+                if !board.is_primary_turn()
+                    || !board.cell_is_free(**cell)
+                    || board.pending_cell.is_some()
+                {
+                    continue;
+                }
+                match (board.mode, &maybe_conn) {
+                    (GameMode::Network, Some(conn)) => {
+                        conn.reducers.mark_cell(board.id(), **cell).unwrap();
+                        // Drawn right away, confirmed or taken back once the server answers
+                        board.pending_cell = Some(**cell);
+                        mark_grid_cell(&mut cmds, entity, &mut color, board.network_primary, &font);
+                        color.0 = color.0.with_alpha(0.5);
+                        cmds.entity(entity).insert(PendingMark);
+                    }
+                    (GameMode::Network, None) => warn!("Network game without a connection"),
+                    (GameMode::VsComputer(_) | GameMode::HotSeat, _) => {
+                        local_move_writer.write(LocalMove(**cell));
+                    }
+                    (GameMode::Spectating, _) => {}
                 }
             }
             Interaction::Hovered => {
@@ -473,6 +1288,259 @@ pub fn grid_cell_interaction(
     }
 }
 
+pub fn refresh_turn_owner_label(
+    turn_owner_label: &mut Text,
+    text_color: &mut TextColor,
+    board: &BoardData,
+) {
+    *turn_owner_label = board.turn_owner_label().into();
+    *text_color = board.turn_owner_label_color().into();
+}
+
+/// Paints a grid cell with the player's colors and letter, marking it as taken
+pub fn mark_grid_cell(
+    cmds: &mut Commands,
+    cell: Entity,
+    background: &mut BackgroundColor,
+    player: Player,
+    font: &Handle<Font>,
+) {
+    let (letter, bg_color, text_color) = mark_style(player);
+    *background = bg_color.into();
+    cmds.entity(cell).insert(CellMarked).with_child((
+        Text::new(letter),
+        TextFont {
+            font: font.clone(),
+            font_size: 40.0,
+            ..default()
+        },
+        TextColor(text_color.into()),
+    ));
+}
+
+/// Redraws every cell from the board's masks, whatever was drawn before. Pending marks are
+/// settled once the server agrees and kept while it has yet to answer
+#[allow(clippy::type_complexity)]
+pub fn render_grid(
+    cmds: &mut Commands,
+    cell_q: &mut Query<(
+        Entity,
+        &GridCell,
+        &mut BackgroundColor,
+        &mut BorderColor,
+        Has<CellMarked>,
+        Has<PendingMark>,
+    )>,
+    board: &mut BoardData,
+    font: &Handle<Font>,
+) {
+    for (entity, cell, mut background, mut border_color, marked, pending) in cell_q.iter_mut() {
+        let Some(owner) = board.cell_owner(**cell) else {
+            if marked && !pending {
+                clear_grid_cell(cmds, entity, &mut background);
+            }
+            continue;
+        };
+
+        if pending {
+            board.pending_cell = None;
+            if owner == board.network_primary {
+                background.0 = background.0.with_alpha(1.);
+                cmds.entity(entity).remove::<PendingMark>();
+            } else {
+                // Somebody else got there first
+                clear_grid_cell(cmds, entity, &mut background);
+                mark_grid_cell(cmds, entity, &mut background, owner, font);
+            }
+        } else if !marked {
+            mark_grid_cell(cmds, entity, &mut background, owner, font);
+            if let Some(number) = board.move_number(**cell).filter(|_| board.is_spectating()) {
+                cmds.entity(entity)
+                    .with_child(move_number_label(number, owner, font));
+            }
+        }
+        *border_color = if board.last_move == Some(**cell) {
+            Color::WHITE.into()
+        } else {
+            colors::GREEN_YELLOW.into()
+        };
+    }
+}
+
+/// Small number in the corner of a marked cell, spectators follow the order the moves came in
+fn move_number_label(number: usize, player: Player, font: &Handle<Font>) -> impl Bundle {
+    let (_, _, text_color) = mark_style(player);
+    (
+        Text::new(number.to_string()),
+        TextFont {
+            font: font.clone(),
+            font_size: 9.0,
+            ..default()
+        },
+        TextColor(text_color.into()),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(2.),
+            left: Val::Px(4.),
+            ..default()
+        },
+    )
+}
+
+/// Wipes a cell's mark, pending or not
+fn clear_grid_cell(cmds: &mut Commands, cell: Entity, background: &mut BackgroundColor) {
+    *background = colors::DODGER_BLUE.into();
+    cmds.entity(cell)
+        .remove::<(CellMarked, PendingMark)>()
+        .despawn_related::<Children>();
+}
+
+/// Takes back a pending mark the server turned down, freeing the cell again
+pub fn rollback_pending_mark(cmds: &mut Commands, cell: Entity, background: &mut BackgroundColor) {
+    clear_grid_cell(cmds, cell, background);
+    cmds.entity(cell)
+        .insert(CellShake(Timer::from_seconds(SHAKE_SECS, TimerMode::Once)));
+}
+
+/// Ends a won game, the winning line gets a moment on the board before the game-over screen
+pub fn end_won_game(cmds: &mut Commands, game_state: &mut NextState<AppState>, board: &BoardData) {
+    if board.winning_mask.is_some() {
+        cmds.insert_resource(GameOverDelay(Timer::from_seconds(
+            WINNING_LINE_SECS,
+            TimerMode::Once,
+        )));
+    } else {
+        // Forfeits have no line to show
+        game_state.set(AppState::GameOverScreen);
+    }
+}
+
+/// Makes the three winning cells glow
+pub fn highlight_winning_line(
+    mut cmds: Commands,
+    mut cell_q: Query<(Entity, &GridCell, &mut BorderColor)>,
+    board: Res<BoardData>,
+) {
+    let Some(winning_mask) = board.winning_mask else {
+        return;
+    };
+    for (entity, cell, mut border_color) in &mut cell_q {
+        if winning_mask & **cell == 0 {
+            continue;
+        }
+        *border_color = Color::WHITE.into();
+        // Drawn over the neighbouring cells so the glow isn't hidden
+        cmds.entity(entity).insert((
+            ZIndex(1),
+            BoxShadow::new(
+                colors::GOLD.into(),
+                Val::Px(0.),
+                Val::Px(0.),
+                Val::Px(3.),
+                Val::Px(10.),
+            ),
+        ));
+    }
+}
+
+pub fn game_over_delay_update(
+    mut cmds: Commands,
+    mut game_over_delay: ResMut<GameOverDelay>,
+    mut game_state: ResMut<NextState<AppState>>,
+    time: Res<Time>,
+) {
+    if game_over_delay.tick(time.delta()).finished() {
+        cmds.remove_resource::<GameOverDelay>();
+        game_state.set(AppState::GameOverScreen);
+    }
+}
+
+/// Leaving mid-highlight must not carry the countdown into the next game
+pub fn clear_game_over_delay(mut cmds: Commands) {
+    cmds.remove_resource::<GameOverDelay>();
+}
+
+/// Wiggles the cells sideways until their shake is over
+pub fn shake_grid_cells(
+    mut cmds: Commands,
+    mut cell_q: Query<(Entity, &mut CellShake, &mut Node)>,
+    time: Res<Time>,
+) {
+    for (entity, mut shake, mut node) in &mut cell_q {
+        if shake.tick(time.delta()).finished() {
+            node.left = Val::Px(0.);
+            cmds.entity(entity).remove::<CellShake>();
+            continue;
+        }
+        // Dies down as the timer runs out
+        let strength = 6. * shake.fraction_remaining();
+        node.left = Val::Px(strength * (shake.elapsed_secs() * 60.).sin());
+    }
+}
+
+fn mark_style(player: Player) -> (&'static str, Srgba, Srgba) {
+    match player {
+        Player::X => ("X", colors::GOLD, colors::DARK_VIOLET),
+        Player::O => ("O", colors::DEEP_PINK, colors::GREEN_YELLOW),
+    }
+}
+
+/// Compact button used by the event screens, `button` tells them apart
+pub fn spawn_small_button(
+    parent: &mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    button: impl Bundle,
+    label: &str,
+) {
+    let button_style = UiButtonStyle {
+        color: colors::GOLD.into(),
+        border_color: colors::DEEP_PINK.into(),
+        text_color: colors::DARK_VIOLET.into(),
+    };
+    parent
+        .spawn((
+            button,
+            Button,
+            button_style,
+            Node {
+                height: Val::Px(20.0),
+                min_width: Val::Px(40.),
+                padding: UiRect::horizontal(Val::Px(3.)),
+                border: UiRect::all(Val::Px(1.0)),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BorderRadius::all(Val::Px(4.0)),
+            BorderColor(button_style.border_color),
+            BackgroundColor(button_style.color),
+        ))
+        .with_child((
+            Text::new(label),
+            TextFont {
+                font: font.clone(),
+                font_size: 13.0,
+                ..default()
+            },
+            TextColor(button_style.text_color),
+        ));
+}
+
+/// Identities share their leading bytes, the tail is what tells players apart
+pub fn short_identity(identity: &Identity) -> String {
+    let hex = identity.to_string();
+    hex[hex.len() - 6..].to_string()
+}
+
+/// Short identity, or "You" for the local player
+pub fn player_label(player: &Identity, me: &Identity) -> String {
+    if player == me {
+        "You".to_string()
+    } else {
+        short_identity(player)
+    }
+}
+
 pub fn clear_board(mut cmds: Commands, grid_q: Query<Entity, With<Grid>>) {
     if let Ok(grid) = grid_q.single() {
         cmds.entity(grid).despawn();
@@ -485,9 +1553,15 @@ pub fn game_over_screen(
     mut cmds: Commands,
     font: Res<FontSpaceGrotesk>,
     board: Res<BoardData>,
-    conn: Res<NetworkConnection>,
+    maybe_conn: Option<Res<NetworkConnection>>,
 ) {
-    conn.reducers.leave_game(board.id()).unwrap();
+    if let Some(conn) = maybe_conn {
+        match board.mode {
+            GameMode::Network => conn.reducers.leave_game(board.id()).unwrap(),
+            GameMode::Spectating => conn.reducers.stop_watching().unwrap(),
+            _ => {}
+        }
+    }
     cmds.spawn((
         StateScoped(AppState::GameOverScreen),
         GameOverScreen,
@@ -539,6 +1613,7 @@ pub fn game_over_screen(
 
         parent
             .spawn((
+                GoBackButton,
                 Button,
                 go_back_button_style,
                 Node {
@@ -576,7 +1651,7 @@ pub fn game_over_screen_interaction(
             &mut BorderColor,
             &Children,
         ),
-        (Changed<Interaction>, With<Button>),
+        (Changed<Interaction>, With<GoBackButton>),
     >,
     mut text_query: Query<&mut TextColor>,
     mut game_state: ResMut<NextState<AppState>>,
@@ -611,7 +1686,7 @@ pub fn lobby_room_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
         StateScoped(AppState::LobbyScreen),
         LobbyRoomScreen,
         Node {
-            width: Val::Percent(100.),
+            width: Val::Px(SCREEN_WIDTH),
             height: Val::Percent(100.),
             align_items: AlignItems::Center,
             justify_content: JustifyContent::SpaceAround,
@@ -671,6 +1746,61 @@ pub fn lobby_room_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
                 ));
             });
 
+        parent
+            .spawn((
+                Label,
+                Text::new("Closes in "),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(colors::DODGER_BLUE.into()),
+            ))
+            .with_child((
+                RoomExpiryCountdown,
+                TextSpan::new("--:--"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(colors::GREEN_YELLOW.into()),
+            ));
+
+        let keep_alive_button_style = UiButtonStyle {
+            color: colors::GREEN_YELLOW.into(),
+            border_color: colors::DODGER_BLUE.into(),
+            text_color: colors::DARK_VIOLET.into(),
+        };
+
+        parent
+            .spawn((
+                KeepAliveButton,
+                Button,
+                keep_alive_button_style,
+                Node {
+                    width: Val::Percent(60.0),
+                    height: Val::Px(25.0),
+                    border: UiRect::all(Val::Px(1.0)),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                BorderRadius::all(Val::Px(10.0)),
+                BorderColor(keep_alive_button_style.border_color),
+                BackgroundColor(keep_alive_button_style.color),
+            ))
+            .with_child((
+                Text::new("Keep Alive"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(keep_alive_button_style.text_color),
+            ));
+
         let go_back_button_style = UiButtonStyle {
             color: colors::GOLD.into(),
             border_color: colors::DEEP_PINK.into(),
@@ -679,6 +1809,7 @@ pub fn lobby_room_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
 
         parent
             .spawn((
+                LeaveRoomButton,
                 Button,
                 go_back_button_style,
                 Node {
@@ -705,23 +1836,67 @@ pub fn lobby_room_screen(mut cmds: Commands, font: Res<FontSpaceGrotesk>) {
     });
 }
 
+pub fn refresh_motd_banner(
+    mut motd_banner_q: Query<&mut Text, With<MotdBanner>>,
+    connection: Res<NetworkConnection>,
+) -> bevy::prelude::Result {
+    let mut motd_banner = motd_banner_q.single_mut()?;
+    **motd_banner = connection
+        .db()
+        .motd()
+        .iter()
+        .next()
+        .map_or(String::new(), |motd| motd.text);
+    Ok(())
+}
+
+/// Rebuilds the lobby list from the cache, narrowed down and ordered by the `LobbyFilter`
 pub fn populate_lobby_from_cache(
     mut cmds: Commands,
     mut lobby_panel_q: Query<Entity, With<LobbyPanel>>,
+    lobby_filter: Res<LobbyFilter>,
     connection: Res<NetworkConnection>,
+    network_auth: Res<NetworkAuth>,
     font: Res<FontSpaceGrotesk>,
 ) -> bevy::prelude::Result {
     let lobby_entity = lobby_panel_q.single_mut()?;
 
+    let mut rooms: Vec<LobbyRoom> = connection
+        .db()
+        .lobby_room()
+        .iter()
+        .filter(|r| lobby_filter.rated.is_none_or(|rated| r.rated == rated))
+        // The server turns their join requests down anyway
+        .filter(|r| !connection.db().block().iter().any(|b| b.blocked == r.owner))
+        .collect();
+    let own_rating = connection
+        .db()
+        .player_rating()
+        .identity()
+        .find(&network_auth.identity)
+        .map_or(DEFAULT_RATING, |r| r.rating);
+    match lobby_filter.sort {
+        LobbySort::Newest => rooms.sort_by_key(|r| std::cmp::Reverse(r.created_at)),
+        LobbySort::ClosestRating => rooms.sort_by_key(|r| r.owner_rating.abs_diff(own_rating)),
+        LobbySort::Variant => rooms.sort_by(|a, b| {
+            (&a.variant, &a.time_control, b.created_at).cmp(&(
+                &b.variant,
+                &b.time_control,
+                a.created_at,
+            ))
+        }),
+    }
+
     cmds.entity(lobby_entity).despawn_related::<Children>();
     cmds.entity(lobby_entity).with_children(|l| {
-        for (idx, (room_id, game_id)) in connection
-            .db()
-            .lobby_room()
-            .iter()
-            .map(|r| (r.id, r.game_id.clone()))
-            .enumerate()
-        {
+        for (idx, room) in rooms.into_iter().enumerate() {
+            let room_id = room.id;
+            // Waiting on the owner to accept
+            let request_sent = connection
+                .db()
+                .join_request()
+                .iter()
+                .any(|r| r.room_id == room_id && r.challenger == network_auth.identity);
             let mut join_button = UiButtonStyle {
                 color: colors::GREEN_YELLOW.into(),
                 border_color: colors::DEEP_PINK.into(),
@@ -755,16 +1930,31 @@ pub fn populate_lobby_from_cache(
                 BorderRadius::all(Val::Px(5.0)),
             ))
             .with_children(|lobby_row| {
-                lobby_row.spawn((
-                    Label,
-                    Text::new(game_id.to_owned()),
-                    TextFont {
-                        font: font.clone(),
-                        font_size: 16.0,
-                        ..default()
-                    },
-                    TextColor(colors::GOLD.into()),
-                ));
+                lobby_row
+                    .spawn((
+                        Label,
+                        Text::new(format!("{} {}", room.owner_nickname, room.owner_rating)),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(colors::GOLD.into()),
+                    ))
+                    .with_child((
+                        TextSpan::new(format!(
+                            "\n{} {} {}",
+                            room.variant,
+                            room.time_control,
+                            if room.rated { "R" } else { "C" }
+                        )),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 9.0,
+                            ..default()
+                        },
+                        TextColor(colors::DODGER_BLUE.into()),
+                    ));
                 lobby_row
                     .spawn((
                         JoinGameButton(room_id),
@@ -784,7 +1974,7 @@ pub fn populate_lobby_from_cache(
                         BackgroundColor(join_button.color),
                     ))
                     .with_child((
-                        Text::new("Join"),
+                        Text::new(if request_sent { "Sent" } else { "Join" }),
                         TextFont {
                             font: font.clone(),
                             font_size: 18.0,
@@ -808,7 +1998,7 @@ pub fn lobby_screen_leave_interaction(
             &mut BorderColor,
             &Children,
         ),
-        (Changed<Interaction>, With<Button>),
+        (Changed<Interaction>, With<LeaveRoomButton>),
     >,
     mut text_query: Query<&mut TextColor>,
     mut game_state: ResMut<NextState<AppState>>,
@@ -838,6 +2028,66 @@ pub fn lobby_screen_leave_interaction(
     }
 }
 
+#[allow(clippy::type_complexity)]
+pub fn keep_alive_button_interaction(
+    mut interaction_query: Query<
+        (
+            &UiButtonStyle,
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        (Changed<Interaction>, With<KeepAliveButton>),
+    >,
+    mut text_query: Query<&mut TextColor>,
+    conn: Res<NetworkConnection>,
+) -> Result {
+    for (button_style, interaction, mut color, mut border_color, children) in &mut interaction_query
+    {
+        let mut text_color = text_query.get_mut(children[0])?;
+        match *interaction {
+            Interaction::Pressed => {
+                *color = Color::WHITE.into();
+                *border_color = button_style.text_color.into();
+                conn.reducers.keep_room_alive()?;
+            }
+            Interaction::Hovered => {
+                *color = button_style.text_color.into();
+                *text_color = button_style.color.into();
+                *border_color = Color::WHITE.into();
+            }
+            Interaction::None => {
+                *color = button_style.color.into();
+                *text_color = button_style.text_color.into();
+                *border_color = button_style.border_color.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Counts down to the room's expiry, keeping it alive pushes it back
+pub fn room_expiry_countdown_update(
+    mut countdown_q: Query<&mut TextSpan, With<RoomExpiryCountdown>>,
+    connection: Res<NetworkConnection>,
+    network_auth: Res<NetworkAuth>,
+) {
+    let Some(room) = connection
+        .db()
+        .lobby_room()
+        .owner()
+        .find(&network_auth.identity)
+    else {
+        return;
+    };
+    let now = Timestamp::now().to_micros_since_unix_epoch();
+    let secs_left = (room.expires_at.to_micros_since_unix_epoch() - now).max(0) / 1_000_000;
+    for mut countdown_label in &mut countdown_q {
+        **countdown_label = format!("{:02}:{:02}", secs_left / 60, secs_left % 60);
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub fn leave_game_button_interaction(
     mut cmds: Commands,
@@ -855,7 +2105,7 @@ pub fn leave_game_button_interaction(
     mut game_state: ResMut<NextState<AppState>>,
     board: Res<BoardData>,
     board_systems: Res<BoardSystems>,
-    conn: Res<NetworkConnection>,
+    maybe_conn: Option<Res<NetworkConnection>>,
 ) {
     for (leave_button, interaction, mut color, mut border_color, children) in &mut interaction_query
     {
@@ -864,7 +2114,13 @@ pub fn leave_game_button_interaction(
             Interaction::Pressed => {
                 *color = Color::WHITE.into();
                 *border_color = leave_button.text_color.into();
-                conn.reducers.leave_game(board.id()).unwrap();
+                if let Some(conn) = &maybe_conn {
+                    match board.mode {
+                        GameMode::Network => conn.reducers.leave_game(board.id()).unwrap(),
+                        GameMode::Spectating => conn.reducers.stop_watching().unwrap(),
+                        _ => {}
+                    }
+                }
                 game_state.set(AppState::HomeScreen);
                 cmds.run_system(board_systems["clear_board"]);
             }
@@ -0,0 +1,142 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// What kind of failure a reducer reported. Reducer errors only reach clients as text, so the
+/// server puts the code in front of the message, `"<code>: <message>"`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ErrorCode {
+    SchedulerOnly,
+    NotAdmin,
+    Banned,
+    RateLimited,
+    UnknownPlayer,
+    NotFound,
+    NotYours,
+    NotParticipant,
+    NotAllowed,
+    AlreadyDone,
+    InvalidArgument,
+    Closed,
+    Full,
+    Blocked,
+    Busy,
+    NoRoom,
+    RoomAlreadyOpen,
+    OwnRoom,
+    GameNotInProgress,
+    NotYourTurn,
+    InvalidCell,
+    CellTaken,
+}
+
+impl ErrorCode {
+    pub const ALL: [ErrorCode; 22] = [
+        ErrorCode::SchedulerOnly,
+        ErrorCode::NotAdmin,
+        ErrorCode::Banned,
+        ErrorCode::RateLimited,
+        ErrorCode::UnknownPlayer,
+        ErrorCode::NotFound,
+        ErrorCode::NotYours,
+        ErrorCode::NotParticipant,
+        ErrorCode::NotAllowed,
+        ErrorCode::AlreadyDone,
+        ErrorCode::InvalidArgument,
+        ErrorCode::Closed,
+        ErrorCode::Full,
+        ErrorCode::Blocked,
+        ErrorCode::Busy,
+        ErrorCode::NoRoom,
+        ErrorCode::RoomAlreadyOpen,
+        ErrorCode::OwnRoom,
+        ErrorCode::GameNotInProgress,
+        ErrorCode::NotYourTurn,
+        ErrorCode::InvalidCell,
+        ErrorCode::CellTaken,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::SchedulerOnly => "scheduler_only",
+            ErrorCode::NotAdmin => "not_admin",
+            ErrorCode::Banned => "banned",
+            ErrorCode::RateLimited => "rate_limited",
+            ErrorCode::UnknownPlayer => "unknown_player",
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::NotYours => "not_yours",
+            ErrorCode::NotParticipant => "not_participant",
+            ErrorCode::NotAllowed => "not_allowed",
+            ErrorCode::AlreadyDone => "already_done",
+            ErrorCode::InvalidArgument => "invalid_argument",
+            ErrorCode::Closed => "closed",
+            ErrorCode::Full => "full",
+            ErrorCode::Blocked => "blocked",
+            ErrorCode::Busy => "busy",
+            ErrorCode::NoRoom => "no_room",
+            ErrorCode::RoomAlreadyOpen => "room_already_open",
+            ErrorCode::OwnRoom => "own_room",
+            ErrorCode::GameNotInProgress => "game_not_in_progress",
+            ErrorCode::NotYourTurn => "not_your_turn",
+            ErrorCode::InvalidCell => "invalid_cell",
+            ErrorCode::CellTaken => "cell_taken",
+        }
+    }
+
+    /// Splits a reducer's error into its code and message, an error without a known code
+    /// comes back whole
+    pub fn split(error: &str) -> (Option<ErrorCode>, &str) {
+        match error.split_once(": ") {
+            Some((code, message)) => match code.parse() {
+                Ok(code) => (Some(code), message),
+                Err(()) => (None, error),
+            },
+            None => (None, error),
+        }
+    }
+}
+
+impl FromStr for ErrorCode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ErrorCode::ALL
+            .into_iter()
+            .find(|code| code.as_str() == s)
+            .ok_or(())
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_parse_back() {
+        for code in ErrorCode::ALL {
+            assert_eq!(code.as_str().parse(), Ok(code));
+        }
+    }
+
+    #[test]
+    fn split_strips_the_code() {
+        assert_eq!(
+            ErrorCode::split("cell_taken: Cell already taken."),
+            (Some(ErrorCode::CellTaken), "Cell already taken.")
+        );
+    }
+
+    #[test]
+    fn split_keeps_unknown_prefixes() {
+        assert_eq!(
+            ErrorCode::split("Banned: cheating"),
+            (None, "Banned: cheating")
+        );
+        assert_eq!(ErrorCode::split("Out of energy"), (None, "Out of energy"));
+    }
+}
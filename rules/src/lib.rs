@@ -0,0 +1,189 @@
+/*
+ * Bitboard:
+ * Map each board cell to one of nine bits (positions 0 to 8):
+ * (0,0) -> bit 0, (1,0) -> bit 1, (2,0) -> bit 2, (0,1) -> bit 3, ..., (2,2) -> bit 8.
+ */
+mod error_code;
+
+pub use error_code::ErrorCode;
+
+pub const WINNING_MASKS: [u16; 8] = [
+    0b000_000_111, // row 0
+    0b000_111_000, // row 1
+    0b111_000_000, // row 2
+    0b001_001_001, // col 0
+    0b010_010_010, // col 1
+    0b100_100_100, // col 2
+    0b100_010_001, // main diagonal
+    0b001_010_100, // anti-diagonal
+];
+
+/// How hard the computer plays, from random moves up to perfect play
+#[derive(Clone, Copy, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+/// The line of three held in `cells`, if any
+pub fn winning_line(cells: u16) -> Option<u16> {
+    WINNING_MASKS
+        .iter()
+        .copied()
+        .find(|&mask| cells & mask == mask)
+}
+
+/// A line stays open until both players hold a cell in it, once none is the game is a draw
+pub fn all_lines_blocked(x_mask: u16, o_mask: u16) -> bool {
+    WINNING_MASKS
+        .iter()
+        .all(|&mask| (mask & x_mask != 0) && (mask & o_mask != 0))
+}
+
+pub fn free_cells(own: u16, other: u16) -> Vec<u16> {
+    (0..9)
+        .map(|bit| 1 << bit)
+        .filter(|&cell| (own | other) & cell == 0)
+        .collect()
+}
+
+/// The cells the computer would be happy to play, callers pick one of them at random
+pub fn candidate_cells(difficulty: Difficulty, own: u16, other: u16) -> Vec<u16> {
+    let free = free_cells(own, other);
+    match difficulty {
+        Difficulty::Easy => free,
+        Difficulty::Medium => {
+            // Take a win or block a loss, otherwise play anywhere
+            let winning = free.iter().find(|&&cell| has_line(own | cell));
+            let blocking = free.iter().find(|&&cell| has_line(other | cell));
+            match winning.or(blocking) {
+                Some(&cell) => vec![cell],
+                None => free,
+            }
+        }
+        Difficulty::Hard => {
+            let scored: Vec<(u16, i8)> = free
+                .iter()
+                .map(|&cell| (cell, -negamax(other, own | cell, -i8::MAX, i8::MAX)))
+                .collect();
+            let Some(best) = scored.iter().map(|&(_, score)| score).max() else {
+                return Vec::new();
+            };
+            // Every move as good as the best, so perfect play doesn't always look the same
+            scored
+                .into_iter()
+                .filter(|&(_, score)| score == best)
+                .map(|(cell, _)| cell)
+                .collect()
+        }
+    }
+}
+
+/// Alpha-beta negamax over the bitboards, scored for the side owning `own` and about to move.
+/// Quicker wins (and slower losses) score higher.
+fn negamax(own: u16, other: u16, mut alpha: i8, beta: i8) -> i8 {
+    let free = free_cells(own, other);
+    if has_line(other) {
+        return -(free.len() as i8 + 1);
+    }
+    if free.is_empty() {
+        return 0;
+    }
+
+    for cell in free {
+        let score = -negamax(other, own | cell, -beta, -alpha);
+        if score > alpha {
+            alpha = score;
+            if alpha >= beta {
+                break;
+            }
+        }
+    }
+    alpha
+}
+
+fn has_line(cells: u16) -> bool {
+    winning_line(cells).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Cells by their bit, reading the board row by row
+    const fn cells(bits: &[u16]) -> u16 {
+        let mut mask = 0;
+        let mut i = 0;
+        while i < bits.len() {
+            mask |= 1 << bits[i];
+            i += 1;
+        }
+        mask
+    }
+
+    #[test]
+    fn winning_line_finds_the_line() {
+        assert_eq!(winning_line(cells(&[0, 1, 2])), Some(0b000_000_111));
+        assert_eq!(winning_line(cells(&[2, 4, 6, 7])), Some(0b001_010_100));
+        assert_eq!(winning_line(cells(&[0, 1, 5, 6])), None);
+        assert_eq!(winning_line(0), None);
+    }
+
+    #[test]
+    fn lines_are_blocked_once_both_players_hold_a_cell_in_each() {
+        // X O X / X O O / O X X
+        let x = cells(&[0, 2, 3, 7, 8]);
+        let o = cells(&[1, 4, 5, 6]);
+        assert!(all_lines_blocked(x, o));
+        // X O X / _ O _ / _ X _, the middle row is still open
+        let x = cells(&[0, 2, 7]);
+        let o = cells(&[1, 4]);
+        assert!(!all_lines_blocked(x, o));
+        assert!(!all_lines_blocked(0, 0));
+    }
+
+    #[test]
+    fn hard_takes_the_win() {
+        // X X _ / O O _ / _ _ _, X to move
+        let own = cells(&[0, 1]);
+        let other = cells(&[3, 4]);
+        assert_eq!(
+            candidate_cells(Difficulty::Hard, own, other),
+            vec![cells(&[2])]
+        );
+    }
+
+    #[test]
+    fn hard_blocks_the_loss() {
+        // O O _ / X _ _ / _ _ _, X to move
+        let own = cells(&[3]);
+        let other = cells(&[0, 1]);
+        assert_eq!(
+            candidate_cells(Difficulty::Hard, own, other),
+            vec![cells(&[2])]
+        );
+    }
+
+    #[test]
+    fn hard_answers_a_corner_with_the_centre() {
+        // Anything but the centre loses against a corner opening
+        let other = cells(&[0]);
+        assert_eq!(
+            candidate_cells(Difficulty::Hard, 0, other),
+            vec![cells(&[4])]
+        );
+    }
+
+    #[test]
+    fn hard_sees_every_opening_as_a_draw() {
+        assert_eq!(candidate_cells(Difficulty::Hard, 0, 0).len(), 9);
+    }
+
+    #[test]
+    fn full_board_has_no_candidates() {
+        let x = cells(&[0, 2, 3, 7, 8]);
+        let o = cells(&[1, 4, 5, 6]);
+        assert!(candidate_cells(Difficulty::Hard, x, o).is_empty());
+    }
+}
@@ -0,0 +1,298 @@
+use std::time::Duration;
+
+use spacetimedb::rand::seq::SliceRandom;
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+
+use crate::errors::GameError;
+use crate::game_table::game as _;
+use crate::lobby_room_table::{close_room, generate_game_id, start_game};
+use crate::presence::{is_busy, is_online};
+use crate::types::{GameState, Player};
+
+const MIN_DURATION_MINUTES: u8 = 5;
+const MAX_DURATION_MINUTES: u8 = 60;
+// How often waiting players are paired up
+const PAIRING_INTERVAL_MS: u64 = 2000;
+// Wins in a row before the points are doubled
+const STREAK_BONUS_AFTER: u8 = 2;
+
+#[spacetimedb::table(name = arena, public)]
+pub struct Arena {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub owner: Identity,
+    pub started_at: Timestamp,
+    pub ends_at: Timestamp,
+    /// Set once the window closes, the standings no longer change after that
+    pub finished: bool,
+}
+
+#[spacetimedb::table(name = arena_player, public)]
+pub struct ArenaPlayer {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub player: Identity,
+    pub points: u32,
+    /// Wins in a row, reset by a draw or a loss
+    pub streak: u8,
+    pub games: u16,
+    /// The arena game being played, `None` while waiting for an opponent
+    pub current_game: Option<String>,
+    /// Cleared when the player leaves or disconnects, they keep their points but stop being
+    /// paired. A player is active in one arena at most
+    pub active: bool,
+}
+
+#[spacetimedb::table(name = arena_pairing_schedule, scheduled(scheduled_arena_pairing))]
+pub struct ArenaPairingSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    #[unique]
+    arena_id: u32,
+}
+
+#[spacetimedb::table(name = arena_end_schedule, scheduled(scheduled_arena_end))]
+pub struct ArenaEndSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    arena_id: u32,
+}
+
+#[spacetimedb::reducer]
+pub fn create_arena(ctx: &ReducerContext, duration_minutes: u8) -> Result<(), GameError> {
+    if !(MIN_DURATION_MINUTES..=MAX_DURATION_MINUTES).contains(&duration_minutes) {
+        return Err(GameError::InvalidArgument(format!(
+            "An arena lasts {MIN_DURATION_MINUTES} to {MAX_DURATION_MINUTES} minutes."
+        )));
+    }
+    if ctx
+        .db
+        .arena()
+        .iter()
+        .any(|a| a.owner == ctx.sender && !a.finished)
+    {
+        return Err(GameError::AlreadyDone("You are already running an arena."));
+    }
+
+    let window = TimeDuration::from_duration(Duration::from_secs(duration_minutes as u64 * 60));
+    let arena = ctx.db.arena().insert(Arena {
+        id: 0,
+        owner: ctx.sender,
+        started_at: ctx.timestamp,
+        ends_at: ctx.timestamp + window,
+        finished: false,
+    });
+
+    let interval = TimeDuration::from_duration(Duration::from_millis(PAIRING_INTERVAL_MS));
+    ctx.db
+        .arena_pairing_schedule()
+        .insert(ArenaPairingSchedule {
+            scheduled_id: 0,
+            scheduled_at: interval.into(),
+            arena_id: arena.id,
+        });
+    ctx.db.arena_end_schedule().insert(ArenaEndSchedule {
+        scheduled_id: 0,
+        scheduled_at: arena.ends_at.into(),
+        arena_id: arena.id,
+    });
+
+    // The owner plays in their own arena
+    join_arena(ctx, arena.id)
+}
+
+#[spacetimedb::reducer]
+pub fn join_arena(ctx: &ReducerContext, arena_id: u32) -> Result<(), GameError> {
+    match ctx.db.arena().id().find(arena_id) {
+        Some(arena) if !arena.finished => {}
+        Some(_) => {
+            return Err(GameError::Closed {
+                kind: "arena",
+                id: arena_id.to_string(),
+            })
+        }
+        None => return Err(GameError::not_found("arena", arena_id)),
+    }
+
+    // Playing here means no longer being paired anywhere else
+    leave_arenas(ctx, ctx.sender);
+    match find_arena_player(ctx, arena_id, ctx.sender) {
+        // Coming back keeps the points earned so far
+        Some(mut arena_player) => {
+            arena_player.active = true;
+            ctx.db.arena_player().id().update(arena_player);
+        }
+        None => {
+            ctx.db.arena_player().insert(ArenaPlayer {
+                id: 0,
+                arena_id,
+                player: ctx.sender,
+                points: 0,
+                streak: 0,
+                games: 0,
+                current_game: None,
+                active: true,
+            });
+        }
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn leave_arena(ctx: &ReducerContext, arena_id: u32) -> Result<(), GameError> {
+    let Some(mut arena_player) = find_arena_player(ctx, arena_id, ctx.sender) else {
+        return Err(GameError::not_found("arena entry", arena_id));
+    };
+    arena_player.active = false;
+    ctx.db.arena_player().id().update(arena_player);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn scheduled_arena_pairing(
+    ctx: &ReducerContext,
+    arg: ArenaPairingSchedule,
+) -> Result<(), GameError> {
+    if ctx.sender != ctx.identity() {
+        return Err(GameError::SchedulerOnly);
+    }
+    if !ctx
+        .db
+        .arena()
+        .id()
+        .find(arg.arena_id)
+        .is_some_and(|a| !a.finished)
+    {
+        ctx.db.arena_pairing_schedule().delete(arg);
+        return Ok(());
+    }
+
+    let mut waiting: Vec<ArenaPlayer> = ctx
+        .db
+        .arena_player()
+        .arena_id()
+        .filter(arg.arena_id)
+        .filter(|p| p.active && p.current_game.is_none())
+        // Gone, or caught up in a game the arena didn't pair
+        .filter(|p| is_online(ctx, p.player) && !is_busy(ctx, p.player))
+        .collect();
+    let mut rng = ctx.rng();
+    waiting.shuffle(&mut rng);
+
+    // Whoever is left over waits for the next pass
+    while let (Some(mut x_player), Some(mut o_player)) = (waiting.pop(), waiting.pop()) {
+        let game_id = generate_game_id(ctx);
+
+        // Nobody waits in the lobby while their arena game is on
+        close_room(ctx, x_player.player);
+        close_room(ctx, o_player.player);
+        start_game(ctx, x_player.player, o_player.player, game_id.clone(), true);
+
+        x_player.current_game = Some(game_id.clone());
+        o_player.current_game = Some(game_id);
+        ctx.db.arena_player().id().update(x_player);
+        ctx.db.arena_player().id().update(o_player);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn scheduled_arena_end(ctx: &ReducerContext, arg: ArenaEndSchedule) -> Result<(), GameError> {
+    if ctx.sender != ctx.identity() {
+        return Err(GameError::SchedulerOnly);
+    }
+    let Some(mut arena) = ctx.db.arena().id().find(arg.arena_id) else {
+        return Ok(());
+    };
+    arena.finished = true;
+    ctx.db.arena().id().update(arena);
+    ctx.db
+        .arena_pairing_schedule()
+        .arena_id()
+        .delete(arg.arena_id);
+
+    // Freeze the standings, games still running no longer count
+    let players: Vec<ArenaPlayer> = ctx
+        .db
+        .arena_player()
+        .arena_id()
+        .filter(arg.arena_id)
+        .collect();
+    for mut arena_player in players {
+        arena_player.active = false;
+        arena_player.current_game = None;
+        ctx.db.arena_player().id().update(arena_player);
+    }
+    log::info!("Arena '{}' finished", arg.arena_id);
+    Ok(())
+}
+
+/// Scores an arena game for both players and frees them up for the next pairing.
+pub fn record_arena_result(ctx: &ReducerContext, game_id: &str, result: GameState) {
+    let players: Vec<ArenaPlayer> = ctx
+        .db
+        .arena_player()
+        .iter()
+        .filter(|p| p.current_game.as_deref() == Some(game_id))
+        .collect();
+    let Some(game) = ctx.db.game().id().find(game_id.to_string()) else {
+        return;
+    };
+
+    for mut arena_player in players {
+        let side = if arena_player.player == game.x_player {
+            Player::X
+        } else {
+            Player::O
+        };
+        let bonus = if arena_player.streak >= STREAK_BONUS_AFTER {
+            2
+        } else {
+            1
+        };
+        match result {
+            GameState::Winner(winner) if winner == side => {
+                arena_player.points += 2 * bonus;
+                arena_player.streak = arena_player.streak.saturating_add(1);
+            }
+            GameState::Draw => {
+                arena_player.points += bonus;
+                arena_player.streak = 0;
+            }
+            _ => arena_player.streak = 0,
+        }
+        arena_player.games += 1;
+        arena_player.current_game = None;
+        ctx.db.arena_player().id().update(arena_player);
+    }
+}
+
+/// Stops `player` from being paired in any arena, what they earned stays on the standings
+pub fn leave_arenas(ctx: &ReducerContext, player: Identity) {
+    let entries: Vec<ArenaPlayer> = ctx
+        .db
+        .arena_player()
+        .iter()
+        .filter(|p| p.player == player && p.active)
+        .collect();
+    for mut arena_player in entries {
+        arena_player.active = false;
+        ctx.db.arena_player().id().update(arena_player);
+    }
+}
+
+fn find_arena_player(ctx: &ReducerContext, arena_id: u32, player: Identity) -> Option<ArenaPlayer> {
+    ctx.db
+        .arena_player()
+        .arena_id()
+        .filter(arena_id)
+        .find(|p| p.player == player)
+}
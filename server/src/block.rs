@@ -0,0 +1,113 @@
+use spacetimedb::{client_visibility_filter, Filter, Identity, ReducerContext, Table, Timestamp};
+
+use crate::errors::GameError;
+use crate::friends::{friendship as _, Friendship};
+use crate::invitation::{invitation as _, Invitation};
+use crate::presence::find_player;
+
+// RLS
+// Only the blocker knows who it blocked
+#[client_visibility_filter]
+const BLOCK_FILTER: Filter = Filter::Sql("SELECT * FROM block WHERE blocker = :sender");
+/// `blocked` can't join, challenge or talk to `blocker` anymore
+#[spacetimedb::table(name = block, public)]
+pub struct Block {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    #[index(btree)]
+    pub blocker: Identity,
+    #[index(btree)]
+    pub blocked: Identity,
+    pub since: Timestamp,
+}
+
+/// Blocks a player, `target` is either their identity or their nickname
+#[spacetimedb::reducer]
+pub fn block_player(ctx: &ReducerContext, target: String) -> Result<(), GameError> {
+    let Some(blocked) = find_player(ctx, &target) else {
+        return Err(GameError::UnknownPlayer(target));
+    };
+    if blocked == ctx.sender || blocked == ctx.identity() {
+        return Err(GameError::NotAllowed("Can't block that player."));
+    }
+    if ctx
+        .db
+        .block()
+        .blocker()
+        .filter(ctx.sender)
+        .any(|b| b.blocked == blocked)
+    {
+        return Ok(());
+    }
+
+    ctx.db.block().insert(Block {
+        id: 0,
+        blocker: ctx.sender,
+        blocked,
+        since: ctx.timestamp,
+    });
+    // So is their friendship, and any pending challenge between the two
+    let friendships: Vec<Friendship> = ctx
+        .db
+        .friendship()
+        .requester()
+        .filter(ctx.sender)
+        .filter(|f| f.addressee == blocked)
+        .chain(
+            ctx.db
+                .friendship()
+                .requester()
+                .filter(blocked)
+                .filter(|f| f.addressee == ctx.sender),
+        )
+        .collect();
+    for friendship in friendships {
+        ctx.db.friendship().delete(friendship);
+    }
+    let invitations: Vec<Invitation> = ctx
+        .db
+        .invitation()
+        .sender()
+        .filter(ctx.sender)
+        .filter(|i| i.recipient == blocked)
+        .chain(
+            ctx.db
+                .invitation()
+                .sender()
+                .filter(blocked)
+                .filter(|i| i.recipient == ctx.sender),
+        )
+        .collect();
+    for invitation in invitations {
+        ctx.db.invitation().delete(invitation);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn unblock_player(ctx: &ReducerContext, block_id: u32) -> Result<(), GameError> {
+    let Some(block) = ctx.db.block().id().find(block_id) else {
+        return Ok(());
+    };
+    if block.blocker != ctx.sender {
+        return Err(GameError::NotYours("block"));
+    }
+    ctx.db.block().delete(block);
+    Ok(())
+}
+
+/// Either of the two players blocked the other
+pub fn is_blocked(ctx: &ReducerContext, a: Identity, b: Identity) -> bool {
+    ctx.db
+        .block()
+        .blocker()
+        .filter(a)
+        .any(|block| block.blocked == b)
+        || ctx
+            .db
+            .block()
+            .blocker()
+            .filter(b)
+            .any(|block| block.blocked == a)
+}
@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use spacetimedb::rand::seq::SliceRandom;
+use spacetimedb::rand::Rng;
+use spacetimedb::{ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+use tictactoe_rules::candidate_cells;
+
+use crate::errors::GameError;
+use crate::game_table::{game as _, Game};
+use crate::game_turn_scheduler::set_turn_expiration_schedule;
+use crate::join_request::withdraw_join_requests;
+use crate::live_game_summary::sync_live_game_summary;
+use crate::lobby_room_table::{close_room, generate_game_id, resolve_side};
+use crate::presence::{is_busy, refresh_presence};
+use crate::types::{BotDifficulty, Player, SidePreference};
+
+// Kept below the shortest turn duration so the bot never times out
+const THINKING_DELAY_MS: u64 = 600;
+const THINKING_JITTER_MS: u64 = 300;
+
+#[spacetimedb::table(name = bot_turn_schedule, scheduled(scheduled_bot_turn))]
+pub struct BotTurnSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    game_id: String,
+    turn: u8,
+}
+
+/// Starts an unrated game against the server-owned bot, which plays as the module identity.
+#[spacetimedb::reducer]
+pub fn play_vs_bot(
+    ctx: &ReducerContext,
+    difficulty: BotDifficulty,
+    side: SidePreference,
+) -> Result<(), GameError> {
+    if ctx.sender == ctx.identity() {
+        return Err(GameError::NotAllowed("The bot can't play against itself."));
+    }
+    if is_busy(ctx, ctx.sender) {
+        return Err(GameError::Busy);
+    }
+    // A player can't wait in the lobby while playing the bot
+    close_room(ctx, ctx.sender);
+    withdraw_join_requests(ctx, ctx.sender);
+
+    let (x_player, o_player) = match resolve_side(ctx, side) {
+        Player::X => (ctx.sender, ctx.identity()),
+        Player::O => (ctx.identity(), ctx.sender),
+    };
+    let mut game = Game::new(x_player, o_player, generate_game_id(ctx));
+    game.bot_difficulty = Some(difficulty);
+    game.rated = false;
+
+    // Schedule Turn Expiration
+    set_turn_expiration_schedule(ctx, game.id.clone(), game.turn);
+    schedule_bot_turn(ctx, &game);
+
+    sync_live_game_summary(ctx, &game);
+    ctx.db.game().insert(game);
+    refresh_presence(ctx, ctx.sender);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn scheduled_bot_turn(ctx: &ReducerContext, arg: BotTurnSchedule) -> Result<(), GameError> {
+    if ctx.sender != ctx.identity() {
+        return Err(GameError::SchedulerOnly);
+    }
+    let Some(game) = ctx.db.game().id().find(arg.game_id) else {
+        return Ok(());
+    };
+    if game.turn != arg.turn || !game.game_in_progress() || !game.validate_turn_owner(ctx.sender) {
+        return Ok(());
+    }
+    let Some(difficulty) = game.bot_difficulty else {
+        return Ok(());
+    };
+
+    let (own, other) = match game.turn_owner {
+        Player::X => (game.x_mask, game.o_mask),
+        Player::O => (game.o_mask, game.x_mask),
+    };
+    let Some(cell) = pick_cell(ctx, difficulty, own, other) else {
+        return Ok(());
+    };
+    game.play_turn(ctx, cell)
+}
+
+/// Schedules the bot's reply if it owns the next turn of a game in progress.
+pub fn schedule_bot_turn(ctx: &ReducerContext, game: &Game) {
+    if game.bot_difficulty.is_none()
+        || !game.game_in_progress()
+        || !game.validate_turn_owner(ctx.identity())
+    {
+        return;
+    }
+
+    let mut rng = ctx.rng();
+    let delay = THINKING_DELAY_MS + rng.gen_range(0..=THINKING_JITTER_MS);
+    let timestamp: Timestamp =
+        ctx.timestamp + TimeDuration::from_duration(Duration::from_millis(delay));
+    ctx.db.bot_turn_schedule().insert(BotTurnSchedule {
+        scheduled_id: 0,
+        scheduled_at: timestamp.into(),
+        game_id: game.id.clone(),
+        turn: game.turn,
+    });
+}
+
+fn pick_cell(ctx: &ReducerContext, difficulty: BotDifficulty, own: u16, other: u16) -> Option<u16> {
+    candidate_cells(difficulty.into(), own, other)
+        .choose(&mut ctx.rng())
+        .copied()
+}
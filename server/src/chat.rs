@@ -0,0 +1,122 @@
+use spacetimedb::{client_visibility_filter, Filter, Identity, ReducerContext, Table, Timestamp};
+
+use crate::block::is_blocked;
+use crate::errors::GameError;
+use crate::game_table::game as _;
+use crate::rate_limit::check_rate_limit;
+use crate::spectator::spectator as _;
+use crate::types::RateLimitCategory;
+
+/// Channel of the messages that aren't tied to a game, game ids never collide with it
+pub const LOBBY_CHANNEL: &str = "lobby";
+const MAX_MESSAGE_LENGTH: usize = 140;
+// Older lobby messages are dropped, game messages go away with their game
+const LOBBY_HISTORY: usize = 50;
+
+// RLS
+// Filters on the same table are combined: everybody reads the lobby, a game's messages are
+// only read by its players and spectators
+#[client_visibility_filter]
+const CHAT_LOBBY_FILTER: Filter = Filter::Sql("SELECT * FROM chat_message WHERE channel = 'lobby'");
+#[client_visibility_filter]
+const CHAT_X_PLAYER_FILTER: Filter = Filter::Sql(
+    "SELECT chat_message.* FROM chat_message JOIN game ON chat_message.channel = game.id WHERE game.x_player = :sender",
+);
+#[client_visibility_filter]
+const CHAT_O_PLAYER_FILTER: Filter = Filter::Sql(
+    "SELECT chat_message.* FROM chat_message JOIN game ON chat_message.channel = game.id WHERE game.o_player = :sender",
+);
+#[client_visibility_filter]
+const CHAT_SPECTATOR_FILTER: Filter = Filter::Sql(
+    "SELECT chat_message.* FROM chat_message JOIN spectator ON chat_message.channel = spectator.game_id WHERE spectator.identity = :sender",
+);
+#[spacetimedb::table(name = chat_message, public)]
+pub struct ChatMessage {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// Either a game id or the lobby channel
+    #[index(btree)]
+    pub channel: String,
+    pub sender: Identity,
+    pub sent_at: Timestamp,
+    pub text: String,
+}
+
+#[spacetimedb::reducer]
+pub fn send_message(ctx: &ReducerContext, channel: String, text: String) -> Result<(), GameError> {
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return Err(GameError::InvalidArgument("Empty message.".to_string()));
+    }
+    if text.chars().count() > MAX_MESSAGE_LENGTH {
+        return Err(GameError::InvalidArgument(format!(
+            "Messages are at most {MAX_MESSAGE_LENGTH} characters."
+        )));
+    }
+    if channel != LOBBY_CHANNEL && !is_participant(ctx, &channel) {
+        return Err(GameError::NotParticipant(channel));
+    }
+    if channel != LOBBY_CHANNEL && blocked_in_game(ctx, &channel) {
+        return Err(GameError::Blocked);
+    }
+    check_rate_limit(ctx, RateLimitCategory::Chat)?;
+
+    ctx.db.chat_message().insert(ChatMessage {
+        id: 0,
+        channel: channel.clone(),
+        sender: ctx.sender,
+        sent_at: ctx.timestamp,
+        text,
+    });
+
+    if channel == LOBBY_CHANNEL {
+        let mut lobby: Vec<ChatMessage> = ctx
+            .db
+            .chat_message()
+            .channel()
+            .filter(LOBBY_CHANNEL)
+            .collect();
+        if lobby.len() > LOBBY_HISTORY {
+            lobby.sort_by_key(|m| m.id);
+            for message in lobby.drain(..lobby.len() - LOBBY_HISTORY) {
+                ctx.db.chat_message().delete(message);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Drops the messages of a game that is going away
+pub fn clear_chat(ctx: &ReducerContext, game_id: &str) {
+    ctx.db.chat_message().channel().delete(game_id);
+}
+
+fn is_participant(ctx: &ReducerContext, game_id: &str) -> bool {
+    let plays = ctx
+        .db
+        .game()
+        .id()
+        .find(game_id.to_string())
+        .is_some_and(|g| g.x_player == ctx.sender || g.o_player == ctx.sender);
+    let watches = ctx
+        .db
+        .spectator()
+        .identity()
+        .find(ctx.sender)
+        .is_some_and(|s| s.game_id == game_id);
+    plays || watches
+}
+
+/// Lobby messages reach everybody, the clients hide the ones of players they blocked
+fn blocked_in_game(ctx: &ReducerContext, game_id: &str) -> bool {
+    ctx.db
+        .game()
+        .id()
+        .find(game_id.to_string())
+        .is_some_and(|g| {
+            [g.x_player, g.o_player]
+                .into_iter()
+                .any(|player| player != ctx.sender && is_blocked(ctx, player, ctx.sender))
+        })
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+
+use tictactoe_rules::ErrorCode;
+
+use crate::types::RateLimitCategory;
+
+/// Why a reducer turned a call down, clients receive its code and message as the failure status
+pub enum GameError {
+    /// A scheduled reducer called by a client
+    SchedulerOnly,
+    NotAdmin,
+    Banned {
+        reason: String,
+        permanent: bool,
+    },
+    RateLimited(RateLimitCategory),
+    UnknownPlayer(String),
+    /// No `kind` with that id, `kind` is what players call it
+    NotFound {
+        kind: &'static str,
+        id: String,
+    },
+    /// The `kind` exists but belongs to somebody else
+    NotYours(&'static str),
+    NotParticipant(String),
+    /// Known, allowed, but not for this player or right now
+    NotAllowed(&'static str),
+    AlreadyDone(&'static str),
+    /// Arguments out of what the reducer accepts, the message says what it expects
+    InvalidArgument(String),
+    Closed {
+        kind: &'static str,
+        id: String,
+    },
+    Full {
+        kind: &'static str,
+        id: String,
+    },
+    Blocked,
+    Busy,
+    NoRoom,
+    RoomAlreadyOpen,
+    OwnRoom,
+    GameNotInProgress(String),
+    NotYourTurn,
+    InvalidCell(u16),
+    CellTaken,
+}
+
+impl GameError {
+    /// What clients match on, the message is only meant to be shown
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            GameError::SchedulerOnly => ErrorCode::SchedulerOnly,
+            GameError::NotAdmin => ErrorCode::NotAdmin,
+            GameError::Banned { .. } => ErrorCode::Banned,
+            GameError::RateLimited(_) => ErrorCode::RateLimited,
+            GameError::UnknownPlayer(_) => ErrorCode::UnknownPlayer,
+            GameError::NotFound { .. } => ErrorCode::NotFound,
+            GameError::NotYours(_) => ErrorCode::NotYours,
+            GameError::NotParticipant(_) => ErrorCode::NotParticipant,
+            GameError::NotAllowed(_) => ErrorCode::NotAllowed,
+            GameError::AlreadyDone(_) => ErrorCode::AlreadyDone,
+            GameError::InvalidArgument(_) => ErrorCode::InvalidArgument,
+            GameError::Closed { .. } => ErrorCode::Closed,
+            GameError::Full { .. } => ErrorCode::Full,
+            GameError::Blocked => ErrorCode::Blocked,
+            GameError::Busy => ErrorCode::Busy,
+            GameError::NoRoom => ErrorCode::NoRoom,
+            GameError::RoomAlreadyOpen => ErrorCode::RoomAlreadyOpen,
+            GameError::OwnRoom => ErrorCode::OwnRoom,
+            GameError::GameNotInProgress(_) => ErrorCode::GameNotInProgress,
+            GameError::NotYourTurn => ErrorCode::NotYourTurn,
+            GameError::InvalidCell(_) => ErrorCode::InvalidCell,
+            GameError::CellTaken => ErrorCode::CellTaken,
+        }
+    }
+
+    pub fn not_found(kind: &'static str, id: impl ToString) -> Self {
+        Self::NotFound {
+            kind,
+            id: id.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.code())?;
+        match self {
+            GameError::SchedulerOnly => {
+                write!(f, "Reducer `scheduled` may not be invoked by clients.")
+            }
+            GameError::NotAdmin => write!(f, "Only admins can do that."),
+            GameError::Banned {
+                reason,
+                permanent: true,
+            } => write!(f, "Banned: {reason}"),
+            GameError::Banned { reason, .. } => write!(f, "Banned for a while: {reason}"),
+            GameError::RateLimited(category) => {
+                let what = match category {
+                    RateLimitCategory::CreateRoom => "rooms created",
+                    RateLimitCategory::Join => "join requests",
+                    RateLimitCategory::Chat => "messages",
+                    RateLimitCategory::Move => "moves",
+                };
+                write!(f, "Slow down, too many {what}.")
+            }
+            GameError::UnknownPlayer(target) => write!(f, "Unknown player '{target}'"),
+            GameError::NotFound { kind, id } => write!(f, "No {kind} '{id}'"),
+            GameError::NotYours(kind) => write!(f, "Not your {kind}."),
+            GameError::NotParticipant(game_id) => write!(f, "Not part of game '{game_id}'"),
+            GameError::NotAllowed(message) | GameError::AlreadyDone(message) => {
+                write!(f, "{message}")
+            }
+            GameError::InvalidArgument(message) => write!(f, "{message}"),
+            GameError::Closed { kind, id } => write!(f, "The {kind} '{id}' is closed."),
+            GameError::Full { kind, id } => write!(f, "The {kind} '{id}' is full."),
+            GameError::Blocked => write!(f, "One of you blocked the other."),
+            GameError::Busy => write!(f, "Finish the current game first."),
+            GameError::NoRoom => write!(f, "You have no room open."),
+            GameError::RoomAlreadyOpen => write!(f, "You already have a room open."),
+            GameError::OwnRoom => write!(f, "Can't join your own room."),
+            GameError::GameNotInProgress(game_id) => {
+                write!(f, "Game '{game_id}' is not in progress.")
+            }
+            GameError::NotYourTurn => write!(f, "Not your turn."),
+            GameError::InvalidCell(cell) => write!(f, "Invalid cell '{cell}'"),
+            GameError::CellTaken => write!(f, "Cell already taken."),
+        }
+    }
+}
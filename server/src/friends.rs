@@ -0,0 +1,99 @@
+use spacetimedb::{client_visibility_filter, Filter, Identity, ReducerContext, Table, Timestamp};
+
+use crate::block::is_blocked;
+use crate::errors::GameError;
+use crate::presence::find_player;
+
+// RLS
+// Filters on the same table are combined, both ends of a friendship see it
+#[client_visibility_filter]
+const FRIENDSHIP_REQUESTER_FILTER: Filter =
+    Filter::Sql("SELECT * FROM friendship WHERE requester = :sender");
+#[client_visibility_filter]
+const FRIENDSHIP_ADDRESSEE_FILTER: Filter =
+    Filter::Sql("SELECT * FROM friendship WHERE addressee = :sender");
+/// Pending until the addressee accepts
+#[spacetimedb::table(name = friendship, public)]
+pub struct Friendship {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    #[index(btree)]
+    pub requester: Identity,
+    #[index(btree)]
+    pub addressee: Identity,
+    pub accepted: bool,
+    pub since: Timestamp,
+}
+
+/// Asks `target`, an identity or a nickname, to be friends
+#[spacetimedb::reducer]
+pub fn friend_request(ctx: &ReducerContext, target: String) -> Result<(), GameError> {
+    let Some(addressee) = find_player(ctx, &target) else {
+        return Err(GameError::UnknownPlayer(target));
+    };
+    if addressee == ctx.sender {
+        return Err(GameError::NotAllowed("Can't befriend yourself."));
+    }
+    if is_blocked(ctx, ctx.sender, addressee) {
+        return Err(GameError::Blocked);
+    }
+    if ctx
+        .db
+        .friendship()
+        .requester()
+        .filter(ctx.sender)
+        .any(|f| f.addressee == addressee)
+    {
+        return Err(GameError::AlreadyDone("Friend request already sent."));
+    }
+    // Asking someone who already asked is agreeing
+    if let Some(reverse) = ctx
+        .db
+        .friendship()
+        .requester()
+        .filter(addressee)
+        .find(|f| f.addressee == ctx.sender)
+    {
+        return accept_friend(ctx, reverse.id);
+    }
+
+    ctx.db.friendship().insert(Friendship {
+        id: 0,
+        requester: ctx.sender,
+        addressee,
+        accepted: false,
+        since: ctx.timestamp,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn accept_friend(ctx: &ReducerContext, friendship_id: u32) -> Result<(), GameError> {
+    let Some(mut friendship) = ctx.db.friendship().id().find(friendship_id) else {
+        return Err(GameError::not_found("friend request", friendship_id));
+    };
+    if friendship.addressee != ctx.sender {
+        return Err(GameError::NotYours("friend request"));
+    }
+    if friendship.accepted {
+        return Ok(());
+    }
+    friendship.accepted = true;
+    friendship.since = ctx.timestamp;
+    ctx.db.friendship().id().update(friendship);
+    Ok(())
+}
+
+/// Ends a friendship, also declines or withdraws a pending request
+#[spacetimedb::reducer]
+pub fn remove_friend(ctx: &ReducerContext, friendship_id: u32) -> Result<(), GameError> {
+    let Some(friendship) = ctx.db.friendship().id().find(friendship_id) else {
+        return Ok(());
+    };
+    if friendship.requester != ctx.sender && friendship.addressee != ctx.sender {
+        return Err(GameError::NotYours("friendship"));
+    }
+    ctx.db.friendship().delete(friendship);
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use spacetimedb::{
+    client_visibility_filter, Filter, Identity, ReducerContext, ScheduleAt, Table, TimeDuration,
+    Timestamp,
+};
+
+use crate::errors::GameError;
+use crate::game_table::game as _;
+use crate::types::Emote;
+
+// How long a reaction stays on screen
+const REACTION_LIFETIME_MS: u64 = 3000;
+
+// RLS
+// Same audience as the game itself: its players and spectators
+#[client_visibility_filter]
+const REACTION_X_PLAYER_FILTER: Filter = Filter::Sql(
+    "SELECT game_reaction.* FROM game_reaction JOIN game ON game_reaction.game_id = game.id WHERE game.x_player = :sender",
+);
+#[client_visibility_filter]
+const REACTION_O_PLAYER_FILTER: Filter = Filter::Sql(
+    "SELECT game_reaction.* FROM game_reaction JOIN game ON game_reaction.game_id = game.id WHERE game.o_player = :sender",
+);
+#[client_visibility_filter]
+const REACTION_SPECTATOR_FILTER: Filter = Filter::Sql(
+    "SELECT game_reaction.* FROM game_reaction JOIN spectator ON game_reaction.game_id = spectator.game_id WHERE spectator.identity = :sender",
+);
+#[spacetimedb::table(name = game_reaction, public)]
+pub struct GameReaction {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub game_id: String,
+    pub sender: Identity,
+    pub emote: Emote,
+    pub sent_at: Timestamp,
+}
+
+#[spacetimedb::table(name = game_reaction_cleanup, scheduled(scheduled_reaction_cleanup))]
+pub struct GameReactionCleanup {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    reaction_id: u64,
+}
+
+#[spacetimedb::reducer]
+pub fn send_reaction(ctx: &ReducerContext, game_id: String, emote: Emote) -> Result<(), GameError> {
+    let Some(game) = ctx.db.game().id().find(game_id.clone()) else {
+        return Err(GameError::not_found("game", game_id));
+    };
+    if ctx.sender != game.x_player && ctx.sender != game.o_player {
+        return Err(GameError::NotParticipant(game_id));
+    }
+
+    // One reaction per player at a time, a new one replaces the last
+    let previous: Vec<GameReaction> = ctx
+        .db
+        .game_reaction()
+        .game_id()
+        .filter(&game_id)
+        .filter(|r| r.sender == ctx.sender)
+        .collect();
+    for reaction in previous {
+        ctx.db.game_reaction().delete(reaction);
+    }
+
+    let reaction = ctx.db.game_reaction().insert(GameReaction {
+        id: 0,
+        game_id,
+        sender: ctx.sender,
+        emote,
+        sent_at: ctx.timestamp,
+    });
+    let lifetime = TimeDuration::from_duration(Duration::from_millis(REACTION_LIFETIME_MS));
+    let timestamp: Timestamp = ctx.timestamp + lifetime;
+    ctx.db.game_reaction_cleanup().insert(GameReactionCleanup {
+        scheduled_id: 0,
+        scheduled_at: timestamp.into(),
+        reaction_id: reaction.id,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn scheduled_reaction_cleanup(
+    ctx: &ReducerContext,
+    arg: GameReactionCleanup,
+) -> Result<(), GameError> {
+    if ctx.sender != ctx.identity() {
+        return Err(GameError::SchedulerOnly);
+    }
+    // Already gone if it was replaced by a newer reaction
+    ctx.db.game_reaction().id().delete(arg.reaction_id);
+    Ok(())
+}
+
+/// Drops the reactions of a game that is going away
+pub fn clear_reactions(ctx: &ReducerContext, game_id: &str) {
+    ctx.db.game_reaction().game_id().delete(game_id);
+}
@@ -1,12 +1,36 @@
-use spacetimedb::{client_visibility_filter, Filter, Identity, ReducerContext, Table};
+use std::time::Duration;
 
+use spacetimedb::{
+    client_visibility_filter, Filter, Identity, ReducerContext, ScheduleAt, Table, TimeDuration,
+    Timestamp,
+};
+use tictactoe_rules::{all_lines_blocked, winning_line};
+
+use crate::arena::record_arena_result;
+use crate::bot_player::schedule_bot_turn;
+use crate::chat::clear_chat;
+use crate::errors::GameError;
+use crate::game_reaction::clear_reactions;
 use crate::game_turn_scheduler::set_turn_expiration_schedule;
-use crate::types::{GameState, Player};
+use crate::live_game_summary::{remove_live_game_summary, sync_live_game_summary};
+use crate::player_rating::update_ratings;
+use crate::presence::refresh_presence;
+use crate::rate_limit::check_rate_limit;
+use crate::rematch::offer_rematch;
+use crate::report::snapshot_game;
+use crate::spectator::clear_spectators;
+use crate::tournament::record_tournament_result;
+use crate::types::{BotDifficulty, GameEndReason, GameState, Player, RateLimitCategory};
 
 // RLS
 #[client_visibility_filter]
 const GAME_ACCESS_FILTER: Filter =
     Filter::Sql("SELECT * FROM game WHERE x_player = :sender OR o_player = :sender");
+// Filters on the same table are combined, spectators see the game they are watching
+#[client_visibility_filter]
+const GAME_SPECTATOR_FILTER: Filter = Filter::Sql(
+    "SELECT game.* FROM game JOIN spectator ON game.id = spectator.game_id WHERE spectator.identity = :sender",
+);
 #[spacetimedb::table(name = game, public)]
 #[derive(Clone)]
 pub struct Game {
@@ -20,37 +44,167 @@ pub struct Game {
     pub state: GameState,
     pub turn: u8,
     pub time_expired: bool,
+    /// Cell marked by the latest move, `None` until the first one
+    pub last_move: Option<u16>,
+    /// Every cell marked so far, in the order they were played
+    pub moves: Vec<u16>,
+    /// Line of the three winning cells, only set when the game was won on the board
+    pub winning_mask: Option<u16>,
+    /// Set when one of the players is the server-owned bot
+    pub bot_difficulty: Option<BotDifficulty>,
+    /// Whether the result should count towards the players' ratings
+    pub rated: bool,
+}
+
+// How long the players have to read why their game ended
+const GAME_END_WINDOW_SECS: u64 = 60;
+
+// RLS
+// Filters on the same table are combined, the row outlives the game so it can't join on it
+#[client_visibility_filter]
+const GAME_END_X_FILTER: Filter = Filter::Sql("SELECT * FROM game_end WHERE x_player = :sender");
+#[client_visibility_filter]
+const GAME_END_O_FILTER: Filter = Filter::Sql("SELECT * FROM game_end WHERE o_player = :sender");
+/// Why a game was deleted, left behind for its players
+#[spacetimedb::table(name = game_end, public)]
+pub struct GameEnd {
+    #[primary_key]
+    pub game_id: String,
+    pub x_player: Identity,
+    pub o_player: Identity,
+    pub reason: GameEndReason,
+    /// `None` for a draw or a game ended without a result
+    pub winner: Option<Player>,
+    pub ended_at: Timestamp,
+}
+
+#[spacetimedb::table(name = game_end_expiry, scheduled(scheduled_game_end_expiry))]
+pub struct GameEndExpiry {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    game_id: String,
 }
 
 #[spacetimedb::reducer]
-pub fn mark_cell(ctx: &ReducerContext, game_id: String, cell: u16) -> Result<(), String> {
-    let Some(mut game) = ctx.db.game().id().find(game_id.clone()) else {
-        return Err("Invalid Game '{game_id}'".to_string());
+pub fn mark_cell(ctx: &ReducerContext, game_id: String, cell: u16) -> Result<(), GameError> {
+    // A single one of the nine cells
+    if cell.count_ones() != 1 || cell >= 1 << 9 {
+        return Err(GameError::InvalidCell(cell));
+    }
+    let Some(game) = ctx.db.game().id().find(game_id.clone()) else {
+        return Err(GameError::not_found("game", game_id));
     };
     if !game.game_in_progress() {
-        return Err("Game '{game_id}' not in progress.".to_string());
+        return Err(GameError::GameNotInProgress(game_id));
     }
     if !game.validate_turn_owner(ctx.sender) {
-        return Err("Not your turn".to_string());
+        return Err(GameError::NotYourTurn);
     }
-    game.toggle_cell(cell)?;
-    game.result_or_next_turn();
-
-    // Schedule Turn Expiration
-    set_turn_expiration_schedule(ctx, game_id, game.turn);
+    if (game.x_mask | game.o_mask) & cell != 0 {
+        return Err(GameError::CellTaken);
+    }
+    check_rate_limit(ctx, RateLimitCategory::Move)?;
+    game.play_turn(ctx, cell)
+}
 
-    ctx.db.game().id().update(game);
+#[spacetimedb::reducer]
+pub fn leave_game(ctx: &ReducerContext, game_id: String) -> Result<(), GameError> {
+    // Already gone when the opponent left first
+    let Some(game) = ctx.db.game().id().find(game_id.clone()) else {
+        return Ok(());
+    };
+    if ctx.sender != game.x_player && ctx.sender != game.o_player {
+        return Err(GameError::NotParticipant(game_id));
+    }
+    forfeit_game(ctx, game, ctx.sender, GameEndReason::Forfeited);
     Ok(())
 }
 
 #[spacetimedb::reducer]
-pub fn leave_game(ctx: &ReducerContext, game_id: String) {
-    let Some(game) = ctx.db.game().id().find(game_id) else {
+pub fn scheduled_game_end_expiry(
+    ctx: &ReducerContext,
+    arg: GameEndExpiry,
+) -> Result<(), GameError> {
+    if ctx.sender != ctx.identity() {
+        return Err(GameError::SchedulerOnly);
+    }
+    ctx.db.game_end().game_id().delete(arg.game_id);
+    Ok(())
+}
+
+/// Deletes a game `player` is leaving, a running one is forfeited to the opponent
+pub fn forfeit_game(ctx: &ReducerContext, game: Game, player: Identity, reason: GameEndReason) {
+    if !game.game_in_progress() {
+        let winner = game.state.winner();
+        delete_game(ctx, game, GameEndReason::Finished, winner);
         return;
-    };
-    if (ctx.sender == game.x_player) || (ctx.sender == game.o_player) {
-        ctx.db.game().delete(game);
     }
+    let result = game.forfeit_result(player);
+    record_game_result(ctx, &game.id, result.clone());
+    delete_game(ctx, game, reason, result.winner());
+}
+
+/// Forfeits every game `player` is still part of, a finished one can sit next to the game that
+/// came after it
+pub fn forfeit_games(ctx: &ReducerContext, player: Identity, reason: GameEndReason) {
+    let games: Vec<Game> = ctx
+        .db
+        .game()
+        .iter()
+        .filter(|g| g.x_player == player || g.o_player == player)
+        .collect();
+    for game in games {
+        forfeit_game(ctx, game, player, reason);
+    }
+}
+
+/// Deletes a game along with everything that only existed for it, a finished game leaves a
+/// rematch offer behind and any game a snapshot to report it. The players are left a `GameEnd`
+/// saying why it went away.
+pub fn delete_game(
+    ctx: &ReducerContext,
+    game: Game,
+    reason: GameEndReason,
+    winner: Option<Player>,
+) {
+    ctx.db.game_end().game_id().delete(game.id.clone());
+    ctx.db.game_end().insert(GameEnd {
+        game_id: game.id.clone(),
+        x_player: game.x_player,
+        o_player: game.o_player,
+        reason,
+        winner,
+        ended_at: ctx.timestamp,
+    });
+    let timestamp: Timestamp =
+        ctx.timestamp + TimeDuration::from_duration(Duration::from_secs(GAME_END_WINDOW_SECS));
+    ctx.db.game_end_expiry().insert(GameEndExpiry {
+        scheduled_id: 0,
+        scheduled_at: timestamp.into(),
+        game_id: game.id.clone(),
+    });
+
+    offer_rematch(ctx, &game);
+    snapshot_game(ctx, &game);
+    clear_spectators(ctx, &game.id);
+    clear_chat(ctx, &game.id);
+    clear_reactions(ctx, &game.id);
+    remove_live_game_summary(ctx, &game.id);
+    let (x_player, o_player) = (game.x_player, game.o_player);
+    ctx.db.game().delete(game);
+    refresh_presence(ctx, x_player);
+    refresh_presence(ctx, o_player);
+}
+
+/// Hands a finished game's result to the ratings and whatever event it was played for
+pub fn record_game_result(ctx: &ReducerContext, game_id: &str, result: GameState) {
+    if let Some(game) = ctx.db.game().id().find(game_id.to_string()) {
+        update_ratings(ctx, &game, &result);
+    }
+    record_tournament_result(ctx, game_id, result.clone());
+    record_arena_result(ctx, game_id, result);
 }
 
 impl Game {
@@ -65,7 +219,29 @@ impl Game {
             state: GameState::InProgress,
             turn: 0,
             time_expired: false,
+            last_move: None,
+            moves: Vec::new(),
+            winning_mask: None,
+            bot_difficulty: None,
+            rated: true,
+        }
+    }
+
+    /// Marks `cell` for the current turn owner, then schedules whatever comes next.
+    pub fn play_turn(mut self, ctx: &ReducerContext, cell: u16) -> Result<(), GameError> {
+        self.toggle_cell(cell)?;
+        self.result_or_next_turn();
+        if !self.game_in_progress() {
+            record_game_result(ctx, &self.id, self.state.clone());
         }
+
+        // Schedule Turn Expiration
+        set_turn_expiration_schedule(ctx, self.id.clone(), self.turn);
+        schedule_bot_turn(ctx, &self);
+
+        sync_live_game_summary(ctx, &self);
+        ctx.db.game().id().update(self);
+        Ok(())
     }
 
     pub fn game_in_progress(&self) -> bool {
@@ -73,6 +249,15 @@ impl Game {
         matches!(self.state, GameState::InProgress)
     }
 
+    /// The result of `player` abandoning the game: their opponent wins.
+    pub fn forfeit_result(&self, player: Identity) -> GameState {
+        if player == self.x_player {
+            GameState::Winner(Player::O)
+        } else {
+            GameState::Winner(Player::X)
+        }
+    }
+
     pub fn validate_turn_owner(&self, player: Identity) -> bool {
         match self.turn_owner {
             Player::X => self.x_player == player,
@@ -81,45 +266,19 @@ impl Game {
     }
 
     pub fn result_or_next_turn(&mut self) {
-        /*
-         * Bitboard:
-         * Map each board cell to one of nine bits (positions 0 to 8):
-         * (0,0) -> bit 0, (1,0) -> bit 1, (2,0) -> bit 2, (0,1) -> bit 3, ..., (2,2) -> bit 8.
-         */
-        const WINNING_MASKS: [u16; 8] = [
-            0b000_000_111, // row 0
-            0b000_111_000, // row 1
-            0b111_000_000, // row 2
-            0b001_001_001, // col 0
-            0b010_010_010, // col 1
-            0b100_100_100, // col 2
-            0b100_010_001, // main diagonal
-            0b001_010_100, // anti-diagonal
-        ];
-
         let curr_player_cells = match self.turn_owner {
             Player::X => self.x_mask,
             Player::O => self.o_mask,
         };
 
-        let mut any_open = false;
-        for &mask in WINNING_MASKS.iter() {
-            // Check for a win on this mask
-            if (curr_player_cells & mask) == mask {
-                self.state = GameState::Winner(self.turn_owner);
-                return;
-            }
-
-            // Check if the line is not yet fully blocked (i.e. it's still open for a win)
-            // Note: We use logical OR so that once any_open is true, it stays true.
-            any_open |= !((mask & self.x_mask != 0) && (mask & self.o_mask != 0));
-        }
-
-        if any_open {
-            self.next_turn();
-        } else {
+        if let Some(mask) = winning_line(curr_player_cells) {
+            self.state = GameState::Winner(self.turn_owner);
+            self.winning_mask = Some(mask);
+        } else if all_lines_blocked(self.x_mask, self.o_mask) {
             // Every mask is blocked, it's a draw.
             self.state = GameState::Draw;
+        } else {
+            self.next_turn();
         }
     }
 
@@ -140,16 +299,18 @@ impl Game {
         self.time_expired = true;
     }
 
-    pub fn toggle_cell(&mut self, cell: u16) -> Result<(), String> {
+    pub fn toggle_cell(&mut self, cell: u16) -> Result<(), GameError> {
         // Check if cell if free
         if !((self.x_mask & cell == 0) && (self.o_mask & cell == 0)) {
-            return Err("Cell already taken".to_string());
+            return Err(GameError::CellTaken);
         }
 
         match self.turn_owner {
             Player::X => self.x_mask |= cell,
             Player::O => self.o_mask |= cell,
         };
+        self.last_move = Some(cell);
+        self.moves.push(cell);
         Ok(())
     }
 }
@@ -2,7 +2,10 @@ use std::time::Duration;
 
 use spacetimedb::{ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
 
+use crate::bot_player::schedule_bot_turn;
+use crate::errors::GameError;
 use crate::game_table::game as _;
+use crate::live_game_summary::sync_live_game_summary;
 
 #[spacetimedb::table(name = game_duration_time_schedule, scheduled(scheduled_turn_expiration))]
 pub struct GameDurationTimeSchedule {
@@ -18,9 +21,9 @@ pub struct GameDurationTimeSchedule {
 pub fn scheduled_turn_expiration(
     ctx: &ReducerContext,
     arg: GameDurationTimeSchedule,
-) -> Result<(), String> {
+) -> Result<(), GameError> {
     if ctx.sender != ctx.identity() {
-        return Err("Reducer `scheduled` may not be invoked by clients.".to_string());
+        return Err(GameError::SchedulerOnly);
     }
     let Some(mut game) = ctx.db.game().id().find(arg.game_id) else {
         return Ok(());
@@ -30,6 +33,8 @@ pub fn scheduled_turn_expiration(
     }
     game.turn_expired();
     set_turn_expiration_schedule(ctx, game.id.clone(), game.turn);
+    schedule_bot_turn(ctx, &game);
+    sync_live_game_summary(ctx, &game);
     ctx.db.game().id().update(game);
     Ok(())
 }
@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use spacetimedb::{
+    client_visibility_filter, Filter, Identity, ReducerContext, ScheduleAt, Table, TimeDuration,
+    Timestamp,
+};
+
+use crate::block::is_blocked;
+use crate::errors::GameError;
+use crate::live_game_summary::nickname;
+use crate::lobby_room_table::{close_room, generate_game_id, resolve_side, start_game};
+use crate::player_rating::rating_of;
+use crate::presence::{find_player, is_busy};
+use crate::types::{Player, SidePreference};
+
+// How long the recipient has to answer
+const INVITATION_WINDOW_SECS: u64 = 60;
+
+// RLS
+// Filters on the same table are combined, only the two players see the challenge
+#[client_visibility_filter]
+const INVITATION_SENDER_FILTER: Filter =
+    Filter::Sql("SELECT * FROM invitation WHERE sender = :sender");
+#[client_visibility_filter]
+const INVITATION_RECIPIENT_FILTER: Filter =
+    Filter::Sql("SELECT * FROM invitation WHERE recipient = :sender");
+/// A direct challenge from one player to another
+#[spacetimedb::table(name = invitation, public)]
+pub struct Invitation {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    #[index(btree)]
+    pub sender: Identity,
+    #[index(btree)]
+    pub recipient: Identity,
+    pub sender_nickname: String,
+    pub sender_rating: u32,
+    /// Side the sender wants to play
+    pub sender_side: SidePreference,
+    pub rated: bool,
+    pub sent_at: Timestamp,
+}
+
+#[spacetimedb::table(name = invitation_expiry, scheduled(scheduled_invitation_expiry))]
+pub struct InvitationExpiry {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    invitation_id: u32,
+}
+
+/// Challenges a player, `target` is either their identity or their nickname
+#[spacetimedb::reducer]
+pub fn challenge_player(
+    ctx: &ReducerContext,
+    target: String,
+    side: SidePreference,
+    rated: bool,
+) -> Result<(), GameError> {
+    let Some(recipient) = find_player(ctx, &target) else {
+        return Err(GameError::UnknownPlayer(target));
+    };
+    if recipient == ctx.sender || recipient == ctx.identity() {
+        return Err(GameError::NotAllowed("Can't challenge that player."));
+    }
+    if is_blocked(ctx, ctx.sender, recipient) {
+        return Err(GameError::Blocked);
+    }
+    if ctx
+        .db
+        .invitation()
+        .sender()
+        .filter(ctx.sender)
+        .any(|i| i.recipient == recipient)
+    {
+        return Err(GameError::AlreadyDone("Challenge already sent."));
+    }
+
+    let invitation = ctx.db.invitation().insert(Invitation {
+        id: 0,
+        sender: ctx.sender,
+        recipient,
+        sender_nickname: nickname(ctx, ctx.sender),
+        sender_rating: rating_of(ctx, ctx.sender),
+        sender_side: side,
+        rated,
+        sent_at: ctx.timestamp,
+    });
+
+    let window = TimeDuration::from_duration(Duration::from_secs(INVITATION_WINDOW_SECS));
+    let timestamp: Timestamp = ctx.timestamp + window;
+    ctx.db.invitation_expiry().insert(InvitationExpiry {
+        scheduled_id: 0,
+        scheduled_at: timestamp.into(),
+        invitation_id: invitation.id,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn accept_invitation(ctx: &ReducerContext, invitation_id: u32) -> Result<(), GameError> {
+    let Some(invitation) = ctx.db.invitation().id().find(invitation_id) else {
+        return Err(GameError::not_found("invitation", invitation_id));
+    };
+    if invitation.recipient != ctx.sender {
+        return Err(GameError::NotYours("invitation"));
+    }
+    if is_busy(ctx, invitation.sender) || is_busy(ctx, invitation.recipient) {
+        return Err(GameError::Busy);
+    }
+
+    let (x_player, o_player) = match resolve_side(ctx, invitation.sender_side) {
+        Player::X => (invitation.sender, invitation.recipient),
+        Player::O => (invitation.recipient, invitation.sender),
+    };
+    // Whatever else they had going is off
+    withdraw_invitations(ctx, invitation.sender);
+    withdraw_invitations(ctx, invitation.recipient);
+    close_room(ctx, invitation.sender);
+    close_room(ctx, invitation.recipient);
+    start_game(
+        ctx,
+        x_player,
+        o_player,
+        generate_game_id(ctx),
+        invitation.rated,
+    );
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn decline_invitation(ctx: &ReducerContext, invitation_id: u32) -> Result<(), GameError> {
+    let Some(invitation) = ctx.db.invitation().id().find(invitation_id) else {
+        return Ok(());
+    };
+    if invitation.recipient != ctx.sender {
+        return Err(GameError::NotYours("invitation"));
+    }
+    ctx.db.invitation().delete(invitation);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn cancel_invitation(ctx: &ReducerContext, invitation_id: u32) -> Result<(), GameError> {
+    let Some(invitation) = ctx.db.invitation().id().find(invitation_id) else {
+        return Ok(());
+    };
+    if invitation.sender != ctx.sender {
+        return Err(GameError::NotYours("invitation"));
+    }
+    ctx.db.invitation().delete(invitation);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn scheduled_invitation_expiry(
+    ctx: &ReducerContext,
+    arg: InvitationExpiry,
+) -> Result<(), GameError> {
+    if ctx.sender != ctx.identity() {
+        return Err(GameError::SchedulerOnly);
+    }
+    // Already gone if it was answered
+    ctx.db.invitation().id().delete(arg.invitation_id);
+    Ok(())
+}
+
+/// Withdraws every challenge `player` sent or received
+pub fn withdraw_invitations(ctx: &ReducerContext, player: Identity) {
+    ctx.db.invitation().sender().delete(player);
+    ctx.db.invitation().recipient().delete(player);
+}
@@ -0,0 +1,125 @@
+use spacetimedb::{client_visibility_filter, Filter, Identity, ReducerContext, Table, Timestamp};
+
+use crate::block::is_blocked;
+use crate::errors::GameError;
+use crate::invitation::withdraw_invitations;
+use crate::live_game_summary::nickname;
+use crate::lobby_room_table::{close_room, lobby_room as _, resolve_side, start_game};
+use crate::moderation::{check_not_banned, is_banned};
+use crate::player_rating::rating_of;
+use crate::presence::is_busy;
+use crate::types::Player;
+
+// RLS
+// Filters on the same table are combined, the challenger and the room's owner see the request
+#[client_visibility_filter]
+const JOIN_REQUEST_CHALLENGER_FILTER: Filter =
+    Filter::Sql("SELECT * FROM join_request WHERE challenger = :sender");
+#[client_visibility_filter]
+const JOIN_REQUEST_OWNER_FILTER: Filter = Filter::Sql(
+    "SELECT join_request.* FROM join_request JOIN lobby_room ON join_request.room_id = lobby_room.id WHERE lobby_room.owner = :sender",
+);
+/// A challenger waiting for the room's owner to let them in
+#[spacetimedb::table(name = join_request, public)]
+pub struct JoinRequest {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    #[index(btree)]
+    pub room_id: u32,
+    #[index(btree)]
+    pub challenger: Identity,
+    pub challenger_nickname: String,
+    pub challenger_rating: u32,
+    pub requested_at: Timestamp,
+}
+
+#[spacetimedb::reducer]
+pub fn accept_join_request(ctx: &ReducerContext, request_id: u32) -> Result<(), GameError> {
+    let Some(request) = ctx.db.join_request().id().find(request_id) else {
+        return Err(GameError::not_found("join request", request_id));
+    };
+    let Some(room) = ctx.db.lobby_room().id().find(request.room_id) else {
+        return Err(GameError::not_found("room", request.room_id));
+    };
+    if room.owner != ctx.sender {
+        return Err(GameError::NotYours("room"));
+    }
+    // Things may have changed since the request was filed
+    check_not_banned(ctx)?;
+    if is_banned(ctx, request.challenger) {
+        return Err(GameError::NotAllowed("That player is banned."));
+    }
+    if is_blocked(ctx, room.owner, request.challenger) {
+        return Err(GameError::Blocked);
+    }
+    if is_busy(ctx, room.owner) || is_busy(ctx, request.challenger) {
+        return Err(GameError::Busy);
+    }
+
+    let (x_player, o_player) = match resolve_side(ctx, room.owner_side) {
+        Player::X => (room.owner, request.challenger),
+        Player::O => (request.challenger, room.owner),
+    };
+    // The other challengers see their request go away with the room, whatever else either
+    // player had going is off
+    close_room(ctx, room.owner);
+    close_room(ctx, request.challenger);
+    withdraw_join_requests(ctx, room.owner);
+    withdraw_join_requests(ctx, request.challenger);
+    withdraw_invitations(ctx, room.owner);
+    withdraw_invitations(ctx, request.challenger);
+    start_game(ctx, x_player, o_player, room.game_id, room.rated);
+    Ok(())
+}
+
+/// Turns a request down, or withdraws it when the challenger calls it
+#[spacetimedb::reducer]
+pub fn decline_join_request(ctx: &ReducerContext, request_id: u32) -> Result<(), GameError> {
+    let Some(request) = ctx.db.join_request().id().find(request_id) else {
+        return Ok(());
+    };
+    let owner = ctx
+        .db
+        .lobby_room()
+        .id()
+        .find(request.room_id)
+        .map(|room| room.owner);
+    if ctx.sender != request.challenger && Some(ctx.sender) != owner {
+        return Err(GameError::NotYours("join request"));
+    }
+    ctx.db.join_request().delete(request);
+    Ok(())
+}
+
+/// Files a request to join the room, one per challenger and room
+pub fn request_to_join(ctx: &ReducerContext, room_id: u32) -> Result<(), GameError> {
+    if ctx
+        .db
+        .join_request()
+        .challenger()
+        .filter(ctx.sender)
+        .any(|r| r.room_id == room_id)
+    {
+        return Err(GameError::AlreadyDone("Join request already sent."));
+    }
+    ctx.db.join_request().insert(JoinRequest {
+        id: 0,
+        room_id,
+        challenger: ctx.sender,
+        challenger_nickname: nickname(ctx, ctx.sender),
+        challenger_rating: rating_of(ctx, ctx.sender),
+        requested_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+/// Drops every request made to a room that is going away
+pub fn clear_join_requests(ctx: &ReducerContext, room_id: u32) {
+    ctx.db.join_request().room_id().delete(room_id);
+}
+
+/// Drops every request `challenger` has pending, it is busy elsewhere now
+pub fn withdraw_join_requests(ctx: &ReducerContext, challenger: Identity) {
+    ctx.db.join_request().challenger().delete(challenger);
+}
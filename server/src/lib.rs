@@ -1,39 +1,89 @@
+mod arena;
+mod block;
+mod bot_player;
+mod chat;
+mod errors;
+mod friends;
+mod game_reaction;
 mod game_table;
 mod game_turn_scheduler;
+mod invitation;
+mod join_request;
+mod live_game_summary;
 mod lobby_room_table;
+mod moderation;
+mod player_rating;
+mod presence;
+mod rate_limit;
+mod rematch;
+mod report;
+mod spectator;
+mod tournament;
 mod types;
 
 use log::info;
 use spacetimedb::{ReducerContext, Table};
 
+use arena::leave_arenas;
+use tournament::leave_tournaments;
+use types::GameEndReason;
+
+pub use block::*;
+pub use chat::*;
+pub use errors::*;
+pub use friends::*;
+pub use game_reaction::*;
 pub use game_table::*;
+pub use invitation::*;
+pub use join_request::*;
+pub use live_game_summary::*;
 pub use lobby_room_table::*;
+pub use moderation::*;
+pub use player_rating::*;
+pub use presence::*;
+pub use rematch::*;
+pub use report::*;
+pub use spectator::*;
 
 #[spacetimedb::reducer(init)]
-pub fn init(_ctx: &ReducerContext) {
+pub fn init(ctx: &ReducerContext) {
     // Called when the module is initially published
+    schedule_lobby_room_cleanup(ctx);
+    seed_admin(ctx);
 }
 
 #[spacetimedb::reducer(client_connected)]
-pub fn identity_connected(ctx: &ReducerContext) {
-    // Called everytime a new client connects
+pub fn identity_connected(ctx: &ReducerContext) -> Result<(), GameError> {
+    // Called everytime a new client connects, banned ones are turned away
     info!("!!!!!! Client connected {}", ctx.sender);
+    check_not_banned(ctx)?;
+    set_online(ctx, ctx.sender);
+    // Modules published before the sweep existed only get it scheduled here
+    schedule_lobby_room_cleanup(ctx);
+    Ok(())
 }
 
 #[spacetimedb::reducer(client_disconnected)]
 pub fn identity_disconnected(ctx: &ReducerContext) {
-    // Delete any open lobbies the client owned
-    if let Some(room) = ctx.db.lobby_room().owner().find(ctx.sender) {
-        ctx.db.lobby_room().delete(room);
-    }
-
-    // We delete any game that the client was part of:
-    if let Some(game) = ctx
-        .db
-        .game()
-        .iter()
-        .find(|t| (t.o_player == ctx.sender) || (t.x_player == ctx.sender))
-    {
-        ctx.db.game().delete(game);
-    }
+    // Delete any open lobbies the client owned, and its requests to join others
+    close_room(ctx, ctx.sender);
+    withdraw_join_requests(ctx, ctx.sender);
+
+    // We delete every game that the client was part of:
+    forfeit_games(ctx, ctx.sender, GameEndReason::Forfeited);
+
+    // Nor is it watching anything anymore
+    leave_spectating(ctx, ctx.sender);
+
+    // Any rematch or challenge with it is off
+    withdraw_rematches(ctx, ctx.sender);
+    withdraw_invitations(ctx, ctx.sender);
+
+    // It can't be paired in an arena it isn't around for
+    leave_arenas(ctx, ctx.sender);
+    // Nor entered in a tournament that hasn't started, running ones forfeit its pairings
+    leave_tournaments(ctx, ctx.sender);
+
+    // Its friends see it go offline
+    set_offline(ctx, ctx.sender);
 }
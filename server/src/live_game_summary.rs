@@ -0,0 +1,92 @@
+use spacetimedb::{Identity, ReducerContext, Table};
+
+use crate::game_table::Game;
+use crate::spectator::spectator as _;
+use crate::types::BotDifficulty;
+
+/// Public digest of a game in progress, the game itself is only visible to its players
+/// and spectators
+#[spacetimedb::table(name = live_game_summary, public)]
+pub struct LiveGameSummary {
+    #[primary_key]
+    pub game_id: String,
+    pub x_nickname: String,
+    pub o_nickname: String,
+    pub variant: String,
+    pub turn: u8,
+    pub spectators: u32,
+}
+
+/// Mirrors a `Game` insert or update, finished games drop out of the list
+pub fn sync_live_game_summary(ctx: &ReducerContext, game: &Game) {
+    if !game.game_in_progress() {
+        remove_live_game_summary(ctx, &game.id);
+        return;
+    }
+
+    let summary = LiveGameSummary {
+        game_id: game.id.clone(),
+        x_nickname: nickname(ctx, game.x_player),
+        o_nickname: nickname(ctx, game.o_player),
+        variant: variant(game),
+        turn: game.turn,
+        spectators: spectator_count(ctx, &game.id),
+    };
+    if ctx
+        .db
+        .live_game_summary()
+        .game_id()
+        .find(game.id.clone())
+        .is_some()
+    {
+        ctx.db.live_game_summary().game_id().update(summary);
+    } else {
+        ctx.db.live_game_summary().insert(summary);
+    }
+}
+
+/// Mirrors a `Game` delete
+pub fn remove_live_game_summary(ctx: &ReducerContext, game_id: &str) {
+    ctx.db
+        .live_game_summary()
+        .game_id()
+        .delete(game_id.to_string());
+}
+
+/// Recounts the spectators after one starts or stops watching `game_id`
+pub fn refresh_spectator_count(ctx: &ReducerContext, game_id: &str) {
+    let Some(mut summary) = ctx
+        .db
+        .live_game_summary()
+        .game_id()
+        .find(game_id.to_string())
+    else {
+        return;
+    };
+    summary.spectators = spectator_count(ctx, game_id);
+    ctx.db.live_game_summary().game_id().update(summary);
+}
+
+/// Players have no names yet, the tail of their identity tells them apart
+pub fn nickname(ctx: &ReducerContext, player: Identity) -> String {
+    if player == ctx.identity() {
+        return "Bot".to_string();
+    }
+    let hex = player.to_string();
+    hex[hex.len() - 6..].to_string()
+}
+
+fn variant(game: &Game) -> String {
+    match game.bot_difficulty {
+        Some(BotDifficulty::Easy) => "Bot Easy",
+        Some(BotDifficulty::Medium) => "Bot Medium",
+        Some(BotDifficulty::Hard) => "Bot Hard",
+        None if game.rated => "Rated",
+        None => "Casual",
+    }
+    .to_string()
+}
+
+fn spectator_count(ctx: &ReducerContext, game_id: &str) -> u32 {
+    ctx.db.spectator().game_id().filter(game_id).count() as u32
+}
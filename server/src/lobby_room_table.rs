@@ -1,8 +1,30 @@
-use spacetimedb::rand::RngCore;
-use spacetimedb::{Identity, ReducerContext, Table};
+use std::time::Duration;
 
+use spacetimedb::rand::{Rng, RngCore};
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+
+use crate::block::is_blocked;
+use crate::errors::GameError;
 use crate::game_table::{game as _, Game};
 use crate::game_turn_scheduler::set_turn_expiration_schedule;
+use crate::join_request::{clear_join_requests, request_to_join, withdraw_join_requests};
+use crate::live_game_summary::{nickname, sync_live_game_summary};
+use crate::moderation::check_not_banned;
+use crate::player_rating::rating_of;
+use crate::presence::refresh_presence;
+use crate::rate_limit::check_rate_limit;
+use crate::types::{Player, RateLimitCategory, SidePreference};
+
+// How long a room stays open without its owner keeping it alive, until an admin changes it
+const DEFAULT_ROOM_TTL_SECS: u32 = 120;
+// Bounds an admin can set the room lifetime within
+const MIN_ROOM_TTL_SECS: u32 = 30;
+const MAX_ROOM_TTL_SECS: u32 = 3600;
+// How often stale rooms are swept away
+const ROOM_CLEANUP_INTERVAL_MS: u64 = 5000;
+// Every room plays the same rules for now
+const VARIANT: &str = "Classic";
+const TIME_CONTROL: &str = "5s to 1.5s";
 
 #[spacetimedb::table(name = lobby_room, public)]
 pub struct LobbyRoom {
@@ -13,15 +35,202 @@ pub struct LobbyRoom {
     pub game_id: String,
     #[unique]
     pub owner: Identity,
+    pub owner_side: SidePreference,
+    pub owner_nickname: String,
+    /// Owner's rating when the room was created
+    pub owner_rating: u32,
+    pub variant: String,
+    pub time_control: String,
+    pub rated: bool,
+    pub created_at: Timestamp,
+    /// Pushed back every time the owner keeps the room alive
+    pub expires_at: Timestamp,
+}
+
+/// Lobby settings admins can change without republishing, a single row
+#[spacetimedb::table(name = lobby_settings)]
+pub struct LobbySettings {
+    #[primary_key]
+    pub id: u8,
+    /// How long a room stays open without its owner keeping it alive
+    pub room_ttl_secs: u32,
+    pub set_by: Identity,
+    pub set_at: Timestamp,
+}
+
+#[spacetimedb::table(name = lobby_room_cleanup, scheduled(scheduled_lobby_room_cleanup))]
+pub struct LobbyRoomCleanup {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
 }
 
 #[spacetimedb::reducer]
-pub fn create_room(ctx: &ReducerContext) {
+pub fn create_room(
+    ctx: &ReducerContext,
+    owner_side: SidePreference,
+    rated: bool,
+) -> Result<(), GameError> {
+    check_not_banned(ctx)?;
     if ctx.db.lobby_room().owner().find(ctx.sender).is_some() {
-        log::warn!("{} is trying to own two games", ctx.sender);
+        return Err(GameError::RoomAlreadyOpen);
+    }
+    check_rate_limit(ctx, RateLimitCategory::CreateRoom)?;
+
+    ctx.db.lobby_room().insert(LobbyRoom {
+        id: 0,
+        game_id: generate_game_id(ctx),
+        owner: ctx.sender,
+        owner_side,
+        owner_nickname: nickname(ctx, ctx.sender),
+        owner_rating: rating_of(ctx, ctx.sender),
+        variant: VARIANT.to_string(),
+        time_control: TIME_CONTROL.to_string(),
+        rated,
+        created_at: ctx.timestamp,
+        expires_at: room_expiry(ctx),
+    });
+    refresh_presence(ctx, ctx.sender);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn keep_room_alive(ctx: &ReducerContext) -> Result<(), GameError> {
+    let Some(mut room) = ctx.db.lobby_room().owner().find(ctx.sender) else {
+        return Err(GameError::NoRoom);
+    };
+    room.expires_at = room_expiry(ctx);
+    ctx.db.lobby_room().id().update(room);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn join_game(ctx: &ReducerContext, room_id: u32) -> Result<(), GameError> {
+    let Some(room) = ctx.db.lobby_room().id().find(room_id) else {
+        return Err(GameError::not_found("room", room_id));
+    };
+    if room.owner == ctx.sender {
+        return Err(GameError::OwnRoom);
+    }
+    check_not_banned(ctx)?;
+    if is_blocked(ctx, room.owner, ctx.sender) {
+        return Err(GameError::Blocked);
+    }
+    check_rate_limit(ctx, RateLimitCategory::Join)?;
+    // The owner decides who gets in, the game starts once the request is accepted
+    request_to_join(ctx, room.id)
+}
+
+#[spacetimedb::reducer]
+pub fn leave_room(ctx: &ReducerContext) -> Result<(), GameError> {
+    if ctx.db.lobby_room().owner().find(ctx.sender).is_none() {
+        return Err(GameError::NoRoom);
+    }
+    close_room(ctx, ctx.sender);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn scheduled_lobby_room_cleanup(
+    ctx: &ReducerContext,
+    _arg: LobbyRoomCleanup,
+) -> Result<(), GameError> {
+    if ctx.sender != ctx.identity() {
+        return Err(GameError::SchedulerOnly);
+    }
+    let stale_rooms: Vec<LobbyRoom> = ctx
+        .db
+        .lobby_room()
+        .iter()
+        .filter(|r| r.expires_at <= ctx.timestamp)
+        .collect();
+    for room in stale_rooms {
+        log::info!("Room {} of {} expired", room.id, room.owner);
+        close_room(ctx, room.owner);
+    }
+    Ok(())
+}
+
+/// Starts sweeping the rooms nobody kept alive, unless it already is
+pub fn schedule_lobby_room_cleanup(ctx: &ReducerContext) {
+    if ctx.db.lobby_room_cleanup().count() > 0 {
         return;
     }
+    let interval = TimeDuration::from_duration(Duration::from_millis(ROOM_CLEANUP_INTERVAL_MS));
+    ctx.db.lobby_room_cleanup().insert(LobbyRoomCleanup {
+        scheduled_id: 0,
+        scheduled_at: interval.into(),
+    });
+}
+
+/// Deletes the room `owner` has open, if any, along with the requests to join it
+pub fn close_room(ctx: &ReducerContext, owner: Identity) {
+    if let Some(room) = ctx.db.lobby_room().owner().find(owner) {
+        clear_join_requests(ctx, room.id);
+        ctx.db.lobby_room().delete(room);
+        refresh_presence(ctx, owner);
+    }
+}
+
+/// Sets how long rooms stay open without being kept alive, rooms already open keep their expiry
+pub fn set_room_ttl(ctx: &ReducerContext, room_ttl_secs: u32) -> Result<(), GameError> {
+    if !(MIN_ROOM_TTL_SECS..=MAX_ROOM_TTL_SECS).contains(&room_ttl_secs) {
+        return Err(GameError::InvalidArgument(format!(
+            "A room stays open between {MIN_ROOM_TTL_SECS} and {MAX_ROOM_TTL_SECS} seconds."
+        )));
+    }
+    let settings = LobbySettings {
+        id: 0,
+        room_ttl_secs,
+        set_by: ctx.sender,
+        set_at: ctx.timestamp,
+    };
+    if ctx.db.lobby_settings().id().find(0).is_some() {
+        ctx.db.lobby_settings().id().update(settings);
+    } else {
+        ctx.db.lobby_settings().insert(settings);
+    }
+    Ok(())
+}
+
+fn room_ttl_secs(ctx: &ReducerContext) -> u32 {
+    ctx.db
+        .lobby_settings()
+        .id()
+        .find(0)
+        .map_or(DEFAULT_ROOM_TTL_SECS, |settings| settings.room_ttl_secs)
+}
+
+fn room_expiry(ctx: &ReducerContext) -> Timestamp {
+    let ttl = Duration::from_secs(room_ttl_secs(ctx) as u64);
+    ctx.timestamp + TimeDuration::from_duration(ttl)
+}
 
+/// Creates the game between two players and starts its first turn timer
+pub fn start_game(
+    ctx: &ReducerContext,
+    x_player: Identity,
+    o_player: Identity,
+    game_id: String,
+    rated: bool,
+) {
+    // Neither of them is free to join another room anymore
+    withdraw_join_requests(ctx, x_player);
+    withdraw_join_requests(ctx, o_player);
+
+    let mut game = Game::new(x_player, o_player, game_id.clone());
+    game.rated = rated;
+    let game = ctx.db.game().insert(game);
+    sync_live_game_summary(ctx, &game);
+    refresh_presence(ctx, x_player);
+    refresh_presence(ctx, o_player);
+
+    // Schedule Turn Expiration
+    set_turn_expiration_schedule(ctx, game_id, 0);
+}
+
+pub fn generate_game_id(ctx: &ReducerContext) -> String {
     // Base58 alphabet excluding ambiguous characters (0, O, I, l)
     const BASE58_ALPHABET: [char; 58] = [
         '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J',
@@ -39,32 +248,21 @@ pub fn create_room(ctx: &ReducerContext) {
         .collect();
 
     log::info!("Game ID generated {id}");
-    ctx.db.lobby_room().insert(LobbyRoom {
-        id: 0,
-        game_id: id.clone(),
-        owner: ctx.sender,
-    });
+    id
 }
 
-#[spacetimedb::reducer]
-pub fn join_game(ctx: &ReducerContext, room_id: u32) {
-    if let Some(room) = ctx.db.lobby_room().id().find(room_id) {
-        if room.owner == ctx.sender {
-            log::warn!("Room owner trying to join his own room.");
-            return;
+/// Resolve a side preference, a random preference is a fair coin flip
+pub fn resolve_side(ctx: &ReducerContext, preference: SidePreference) -> Player {
+    match preference {
+        SidePreference::X => Player::X,
+        SidePreference::O => Player::O,
+        SidePreference::Random => {
+            let mut rng = ctx.rng();
+            if rng.gen_bool(0.5) {
+                Player::X
+            } else {
+                Player::O
+            }
         }
-        ctx.db
-            .game()
-            .insert(Game::new(room.owner, ctx.sender, room.game_id.clone()));
-
-        // Schedule Turn Expiration
-        set_turn_expiration_schedule(ctx, room.game_id.clone(), 0);
-
-        ctx.db.lobby_room().delete(room);
-    };
-}
-
-#[spacetimedb::reducer]
-pub fn leave_room(ctx: &ReducerContext) {
-    ctx.db.lobby_room().owner().delete(ctx.sender);
+    }
 }
@@ -0,0 +1,195 @@
+use std::time::Duration;
+
+use spacetimedb::{Identity, ReducerContext, Table, TimeDuration, Timestamp};
+
+use crate::arena::record_arena_result;
+use crate::errors::GameError;
+use crate::game_table::{delete_game, forfeit_games, game as _};
+use crate::invitation::withdraw_invitations;
+use crate::join_request::withdraw_join_requests;
+use crate::lobby_room_table::{close_room, lobby_room as _, set_room_ttl};
+use crate::presence::find_player;
+use crate::tournament::record_tournament_result;
+use crate::types::{GameEndReason, GameState};
+
+const MAX_MOTD_LENGTH: usize = 140;
+
+/// Identities allowed to moderate, the one that published the module is the first
+#[spacetimedb::table(name = admin)]
+pub struct Admin {
+    #[primary_key]
+    pub identity: Identity,
+    pub granted_at: Timestamp,
+}
+
+#[spacetimedb::table(name = ban)]
+pub struct Ban {
+    #[primary_key]
+    pub identity: Identity,
+    pub reason: String,
+    pub banned_by: Identity,
+    pub banned_at: Timestamp,
+    /// `None` for a permanent ban
+    pub expires_at: Option<Timestamp>,
+}
+
+/// Message of the day, a single row shown on everybody's home screen
+#[spacetimedb::table(name = motd, public)]
+pub struct Motd {
+    #[primary_key]
+    pub id: u8,
+    pub text: String,
+    pub set_at: Timestamp,
+}
+
+/// Bans a player for `minutes`, or for good when `minutes` is 0. Whatever it was playing is
+/// forfeited.
+#[spacetimedb::reducer]
+pub fn ban_player(
+    ctx: &ReducerContext,
+    target: String,
+    minutes: u32,
+    reason: String,
+) -> Result<(), GameError> {
+    check_admin(ctx)?;
+    let Some(banned) = find_player(ctx, &target) else {
+        return Err(GameError::UnknownPlayer(target));
+    };
+    if is_admin(ctx, banned) {
+        return Err(GameError::NotAllowed("Can't ban an admin."));
+    }
+
+    let expires_at = (minutes > 0).then(|| {
+        ctx.timestamp + TimeDuration::from_duration(Duration::from_secs(minutes as u64 * 60))
+    });
+    let ban = Ban {
+        identity: banned,
+        reason,
+        banned_by: ctx.sender,
+        banned_at: ctx.timestamp,
+        expires_at,
+    };
+    if ctx.db.ban().identity().find(banned).is_some() {
+        ctx.db.ban().identity().update(ban);
+    } else {
+        ctx.db.ban().insert(ban);
+    }
+
+    close_room(ctx, banned);
+    withdraw_join_requests(ctx, banned);
+    withdraw_invitations(ctx, banned);
+    forfeit_games(ctx, banned, GameEndReason::Banned);
+    log::info!("{banned} banned by {}", ctx.sender);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn unban_player(ctx: &ReducerContext, target: String) -> Result<(), GameError> {
+    check_admin(ctx)?;
+    let Some(banned) = find_player(ctx, &target) else {
+        return Err(GameError::UnknownPlayer(target));
+    };
+    ctx.db.ban().identity().delete(banned);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn admin_close_room(ctx: &ReducerContext, room_id: u32) -> Result<(), GameError> {
+    check_admin(ctx)?;
+    let Some(room) = ctx.db.lobby_room().id().find(room_id) else {
+        return Err(GameError::not_found("room", room_id));
+    };
+    close_room(ctx, room.owner);
+    Ok(())
+}
+
+/// Sets how many seconds a room stays open without its owner keeping it alive
+#[spacetimedb::reducer]
+pub fn admin_set_room_ttl(ctx: &ReducerContext, room_ttl_secs: u32) -> Result<(), GameError> {
+    check_admin(ctx)?;
+    set_room_ttl(ctx, room_ttl_secs)
+}
+
+/// Ends a game on the spot, without a result
+#[spacetimedb::reducer]
+pub fn terminate_game(ctx: &ReducerContext, game_id: String) -> Result<(), GameError> {
+    check_admin(ctx)?;
+    let Some(game) = ctx.db.game().id().find(game_id.clone()) else {
+        return Err(GameError::not_found("game", game_id));
+    };
+    // The ratings don't move, the event it was played for goes on as if it was drawn
+    if game.game_in_progress() {
+        record_tournament_result(ctx, &game.id, GameState::Draw);
+        record_arena_result(ctx, &game.id, GameState::Draw);
+    }
+    delete_game(ctx, game, GameEndReason::Terminated, None);
+    Ok(())
+}
+
+/// Sets the message of the day, an empty text takes it down
+#[spacetimedb::reducer]
+pub fn set_motd(ctx: &ReducerContext, text: String) -> Result<(), GameError> {
+    check_admin(ctx)?;
+    let text = text.trim().to_string();
+    if text.chars().count() > MAX_MOTD_LENGTH {
+        return Err(GameError::InvalidArgument(format!(
+            "The message of the day is at most {MAX_MOTD_LENGTH} characters."
+        )));
+    }
+
+    ctx.db.motd().id().delete(0);
+    if !text.is_empty() {
+        ctx.db.motd().insert(Motd {
+            id: 0,
+            text,
+            set_at: ctx.timestamp,
+        });
+    }
+    Ok(())
+}
+
+/// Makes whoever published the module an admin
+pub fn seed_admin(ctx: &ReducerContext) {
+    if !is_admin(ctx, ctx.sender) {
+        ctx.db.admin().insert(Admin {
+            identity: ctx.sender,
+            granted_at: ctx.timestamp,
+        });
+    }
+}
+
+/// Fails while `ctx.sender` is banned, lifting the ban once it ran out
+pub fn check_not_banned(ctx: &ReducerContext) -> Result<(), GameError> {
+    let Some(ban) = ctx.db.ban().identity().find(ctx.sender) else {
+        return Ok(());
+    };
+    match ban.expires_at {
+        Some(expires_at) if expires_at <= ctx.timestamp => {
+            ctx.db.ban().identity().delete(ctx.sender);
+            Ok(())
+        }
+        expires_at => Err(GameError::Banned {
+            reason: ban.reason,
+            permanent: expires_at.is_none(),
+        }),
+    }
+}
+
+/// Whether `player` is serving a ban that hasn't run out yet
+pub fn is_banned(ctx: &ReducerContext, player: Identity) -> bool {
+    ctx.db.ban().identity().find(player).is_some_and(|ban| {
+        ban.expires_at
+            .is_none_or(|expires_at| expires_at > ctx.timestamp)
+    })
+}
+
+fn is_admin(ctx: &ReducerContext, identity: Identity) -> bool {
+    ctx.db.admin().identity().find(identity).is_some()
+}
+
+pub fn check_admin(ctx: &ReducerContext) -> Result<(), GameError> {
+    if !is_admin(ctx, ctx.sender) {
+        return Err(GameError::NotAdmin);
+    }
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use spacetimedb::{Identity, ReducerContext, Table};
+
+use crate::game_table::Game;
+use crate::types::{GameState, Player};
+
+/// Rating of a player who hasn't finished a rated game yet
+pub const DEFAULT_RATING: u32 = 1200;
+// How much a single game can move a rating
+const K_FACTOR: f64 = 32.;
+
+#[spacetimedb::table(name = player_rating, public)]
+pub struct PlayerRating {
+    #[primary_key]
+    pub identity: Identity,
+    pub rating: u32,
+    pub games_played: u32,
+}
+
+pub fn rating_of(ctx: &ReducerContext, player: Identity) -> u32 {
+    ctx.db
+        .player_rating()
+        .identity()
+        .find(player)
+        .map_or(DEFAULT_RATING, |r| r.rating)
+}
+
+/// Elo update of both players once a rated game has its result
+pub fn update_ratings(ctx: &ReducerContext, game: &Game, result: &GameState) {
+    if !game.rated || game.bot_difficulty.is_some() {
+        return;
+    }
+    let x_score = match result {
+        GameState::InProgress => return,
+        GameState::Draw => 0.5,
+        GameState::Winner(Player::X) => 1.,
+        GameState::Winner(Player::O) => 0.,
+    };
+
+    let x_rating = rating_of(ctx, game.x_player) as f64;
+    let o_rating = rating_of(ctx, game.o_player) as f64;
+    let x_delta = rating_delta(x_rating, o_rating, x_score);
+
+    set_rating(ctx, game.x_player, x_rating + x_delta);
+    set_rating(ctx, game.o_player, o_rating - x_delta);
+}
+
+/// Points X gains, and O loses, for scoring `x_score` (1 for a win, 0.5 for a draw)
+fn rating_delta(x_rating: f64, o_rating: f64, x_score: f64) -> f64 {
+    let x_expected = 1. / (1. + 10f64.powf((o_rating - x_rating) / 400.));
+    K_FACTOR * (x_score - x_expected)
+}
+
+fn set_rating(ctx: &ReducerContext, player: Identity, rating: f64) {
+    let rating = rating.round().max(0.) as u32;
+    match ctx.db.player_rating().identity().find(player) {
+        Some(mut player_rating) => {
+            player_rating.rating = rating;
+            player_rating.games_played += 1;
+            ctx.db.player_rating().identity().update(player_rating);
+        }
+        None => {
+            ctx.db.player_rating().insert(PlayerRating {
+                identity: player,
+                rating,
+                games_played: 1,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn even_players_split_the_k_factor() {
+        assert_eq!(rating_delta(1200., 1200., 1.), K_FACTOR / 2.);
+        assert_eq!(rating_delta(1200., 1200., 0.), -K_FACTOR / 2.);
+        assert_eq!(rating_delta(1200., 1200., 0.5), 0.);
+    }
+
+    #[test]
+    fn upsets_move_ratings_more() {
+        // 400 points apart, the favourite is expected to score 10/11
+        let favourite_wins = rating_delta(1600., 1200., 1.);
+        let underdog_wins = rating_delta(1200., 1600., 1.);
+        assert!((favourite_wins - K_FACTOR / 11.).abs() < 1e-9);
+        assert!((underdog_wins - K_FACTOR * 10. / 11.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn a_draw_costs_the_favourite() {
+        assert!(rating_delta(1600., 1200., 0.5) < 0.);
+        assert!(rating_delta(1200., 1600., 0.5) > 0.);
+    }
+}
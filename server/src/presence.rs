@@ -0,0 +1,99 @@
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+use crate::game_table::game as _;
+use crate::live_game_summary::nickname;
+use crate::lobby_room_table::lobby_room as _;
+use crate::spectator::spectator as _;
+use crate::types::PresenceStatus;
+
+/// Every player that ever connected, kept up to date by the reducers that change their status
+#[spacetimedb::table(name = presence, public)]
+pub struct Presence {
+    #[primary_key]
+    pub identity: Identity,
+    #[index(btree)]
+    pub nickname: String,
+    pub status: PresenceStatus,
+    pub last_seen: Timestamp,
+}
+
+pub fn set_online(ctx: &ReducerContext, player: Identity) {
+    let presence = Presence {
+        identity: player,
+        nickname: nickname(ctx, player),
+        status: current_status(ctx, player),
+        last_seen: ctx.timestamp,
+    };
+    if ctx.db.presence().identity().find(player).is_some() {
+        ctx.db.presence().identity().update(presence);
+    } else {
+        ctx.db.presence().insert(presence);
+    }
+}
+
+pub fn set_offline(ctx: &ReducerContext, player: Identity) {
+    if let Some(mut presence) = ctx.db.presence().identity().find(player) {
+        presence.status = PresenceStatus::Offline;
+        presence.last_seen = ctx.timestamp;
+        ctx.db.presence().identity().update(presence);
+    }
+}
+
+/// Follows a player into or out of the lobby and games, offline players stay offline
+pub fn refresh_presence(ctx: &ReducerContext, player: Identity) {
+    let Some(mut presence) = ctx.db.presence().identity().find(player) else {
+        return;
+    };
+    let status = current_status(ctx, player);
+    if presence.status == PresenceStatus::Offline || presence.status == status {
+        return;
+    }
+    presence.status = status;
+    presence.last_seen = ctx.timestamp;
+    ctx.db.presence().identity().update(presence);
+}
+
+/// Connected right now
+pub fn is_online(ctx: &ReducerContext, player: Identity) -> bool {
+    ctx.db
+        .presence()
+        .identity()
+        .find(player)
+        .is_some_and(|p| p.status != PresenceStatus::Offline)
+}
+
+/// Playing or watching a game
+pub fn is_busy(ctx: &ReducerContext, player: Identity) -> bool {
+    ctx.db.spectator().identity().find(player).is_some() || is_playing(ctx, player)
+}
+
+/// Part of a game that is still going on
+pub fn is_playing(ctx: &ReducerContext, player: Identity) -> bool {
+    ctx.db
+        .game()
+        .iter()
+        .any(|g| g.game_in_progress() && (g.x_player == player || g.o_player == player))
+}
+
+/// Looks a player up by identity, or by the nickname of anyone who ever connected
+pub fn find_player(ctx: &ReducerContext, target: &str) -> Option<Identity> {
+    if let Ok(identity) = Identity::from_hex(target) {
+        return Some(identity);
+    }
+    ctx.db
+        .presence()
+        .nickname()
+        .filter(target)
+        .next()
+        .map(|p| p.identity)
+}
+
+fn current_status(ctx: &ReducerContext, player: Identity) -> PresenceStatus {
+    if is_playing(ctx, player) {
+        PresenceStatus::InGame
+    } else if ctx.db.lobby_room().owner().find(player).is_some() {
+        PresenceStatus::InLobby
+    } else {
+        PresenceStatus::Online
+    }
+}
@@ -0,0 +1,103 @@
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+use crate::errors::GameError;
+use crate::types::RateLimitCategory;
+
+/// Token bucket of an identity for one category of reducers
+#[spacetimedb::table(name = rate_limit)]
+pub struct RateLimit {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    identity: Identity,
+    category: RateLimitCategory,
+    tokens: f64,
+    refilled_at: Timestamp,
+}
+
+impl RateLimitCategory {
+    /// How many calls can be made in a burst, and how many tokens come back every second
+    fn bucket(&self) -> (f64, f64) {
+        match self {
+            RateLimitCategory::CreateRoom => (3., 0.1),
+            RateLimitCategory::Join => (5., 0.5),
+            RateLimitCategory::Chat => (5., 0.5),
+            RateLimitCategory::Move => (10., 4.),
+        }
+    }
+}
+
+/// Takes a token from `ctx.sender`'s bucket, fails once it is empty. A reducer returning an error
+/// rolls its token back along with everything else, so it is called once the arguments are
+/// checked, right before the reducer writes.
+pub fn check_rate_limit(
+    ctx: &ReducerContext,
+    category: RateLimitCategory,
+) -> Result<(), GameError> {
+    let (capacity, refill_per_sec) = category.bucket();
+    let Some(mut limit) = ctx
+        .db
+        .rate_limit()
+        .identity()
+        .filter(ctx.sender)
+        .find(|l| l.category == category)
+    else {
+        ctx.db.rate_limit().insert(RateLimit {
+            id: 0,
+            identity: ctx.sender,
+            category,
+            tokens: capacity - 1.,
+            refilled_at: ctx.timestamp,
+        });
+        return Ok(());
+    };
+
+    let elapsed = ctx
+        .timestamp
+        .duration_since(limit.refilled_at)
+        .map_or(0., |elapsed| elapsed.as_secs_f64());
+    limit.tokens = refill(limit.tokens, elapsed, capacity, refill_per_sec);
+    limit.refilled_at = ctx.timestamp;
+    if limit.tokens < 1. {
+        return Err(GameError::RateLimited(category));
+    }
+    limit.tokens -= 1.;
+    ctx.db.rate_limit().id().update(limit);
+    Ok(())
+}
+
+/// Tokens in a bucket left alone for `elapsed` seconds, it never holds more than `capacity`
+fn refill(tokens: f64, elapsed: f64, capacity: f64, refill_per_sec: f64) -> f64 {
+    (tokens + elapsed * refill_per_sec).min(capacity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refills_with_time() {
+        assert_eq!(refill(0., 2., 10., 4.), 8.);
+        assert_eq!(refill(2.5, 0., 10., 4.), 2.5);
+    }
+
+    #[test]
+    fn refill_stops_at_capacity() {
+        assert_eq!(refill(9., 60., 10., 4.), 10.);
+    }
+
+    #[test]
+    fn every_category_refills_a_call_eventually() {
+        for category in [
+            RateLimitCategory::CreateRoom,
+            RateLimitCategory::Join,
+            RateLimitCategory::Chat,
+            RateLimitCategory::Move,
+        ] {
+            let (capacity, refill_per_sec) = category.bucket();
+            assert!(capacity >= 1.);
+            assert!(refill(0., 1. / refill_per_sec, capacity, refill_per_sec) >= 1.);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::time::Duration;
+
+use spacetimedb::{
+    client_visibility_filter, Filter, Identity, ReducerContext, ScheduleAt, Table, TimeDuration,
+    Timestamp,
+};
+
+use crate::errors::GameError;
+use crate::game_table::Game;
+use crate::lobby_room_table::{close_room, generate_game_id, start_game};
+use crate::types::Player;
+
+// How long the players have to agree once the game is over
+const REMATCH_WINDOW_SECS: u64 = 30;
+
+// RLS
+// Filters on the same table are combined, either player sees the offer
+#[client_visibility_filter]
+const REMATCH_X_PLAYER_FILTER: Filter =
+    Filter::Sql("SELECT * FROM rematch WHERE x_player = :sender");
+#[client_visibility_filter]
+const REMATCH_O_PLAYER_FILTER: Filter =
+    Filter::Sql("SELECT * FROM rematch WHERE o_player = :sender");
+/// Open for a little while after a finished game, keyed by that game's id
+#[spacetimedb::table(name = rematch, public)]
+#[derive(Clone)]
+pub struct Rematch {
+    #[primary_key]
+    pub game_id: String,
+    pub x_player: Identity,
+    pub o_player: Identity,
+    pub x_wants: bool,
+    pub o_wants: bool,
+    /// Carried over from the finished game
+    pub rated: bool,
+}
+
+#[spacetimedb::table(name = rematch_expiry, scheduled(scheduled_rematch_expiry))]
+pub struct RematchExpiry {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    game_id: String,
+}
+
+#[spacetimedb::reducer]
+pub fn request_rematch(ctx: &ReducerContext, game_id: String) -> Result<(), GameError> {
+    let Some(mut rematch) = ctx.db.rematch().game_id().find(game_id.clone()) else {
+        return Err(GameError::not_found("rematch", game_id));
+    };
+    let Some(side) = rematch.side_of(ctx.sender) else {
+        return Err(GameError::NotParticipant(game_id));
+    };
+    if rematch.wants(side) {
+        return Err(GameError::AlreadyDone("Rematch already requested."));
+    }
+    // Asking once the opponent already did is agreeing to it
+    if rematch.opponent_wants(side) {
+        return accept_rematch(ctx, game_id);
+    }
+
+    match side {
+        Player::X => rematch.x_wants = true,
+        Player::O => rematch.o_wants = true,
+    }
+    ctx.db.rematch().game_id().update(rematch);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn accept_rematch(ctx: &ReducerContext, game_id: String) -> Result<(), GameError> {
+    let Some(rematch) = ctx.db.rematch().game_id().find(game_id.clone()) else {
+        return Err(GameError::not_found("rematch", game_id));
+    };
+    let Some(side) = rematch.side_of(ctx.sender) else {
+        return Err(GameError::NotParticipant(game_id));
+    };
+    if !rematch.opponent_wants(side) {
+        return Err(GameError::NotAllowed(
+            "Your opponent hasn't asked for a rematch.",
+        ));
+    }
+
+    ctx.db.rematch().delete(rematch.clone());
+    // Nobody waits in the lobby while their rematch is on
+    close_room(ctx, rematch.x_player);
+    close_room(ctx, rematch.o_player);
+
+    // Sides are swapped so the first move alternates
+    start_game(
+        ctx,
+        rematch.o_player,
+        rematch.x_player,
+        generate_game_id(ctx),
+        rematch.rated,
+    );
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn decline_rematch(ctx: &ReducerContext, game_id: String) -> Result<(), GameError> {
+    let Some(rematch) = ctx.db.rematch().game_id().find(game_id.clone()) else {
+        return Ok(());
+    };
+    if rematch.side_of(ctx.sender).is_none() {
+        return Err(GameError::NotParticipant(game_id));
+    }
+    ctx.db.rematch().delete(rematch);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn scheduled_rematch_expiry(ctx: &ReducerContext, arg: RematchExpiry) -> Result<(), GameError> {
+    if ctx.sender != ctx.identity() {
+        return Err(GameError::SchedulerOnly);
+    }
+    // Already gone if it was accepted or declined
+    ctx.db.rematch().game_id().delete(arg.game_id);
+    Ok(())
+}
+
+/// Opens a rematch between the players of a game that just ended, bots don't ask for one
+pub fn offer_rematch(ctx: &ReducerContext, game: &Game) {
+    if game.game_in_progress() || game.bot_difficulty.is_some() {
+        return;
+    }
+    ctx.db.rematch().insert(Rematch {
+        game_id: game.id.clone(),
+        x_player: game.x_player,
+        o_player: game.o_player,
+        x_wants: false,
+        o_wants: false,
+        rated: game.rated,
+    });
+
+    let window = TimeDuration::from_duration(Duration::from_secs(REMATCH_WINDOW_SECS));
+    let timestamp: Timestamp = ctx.timestamp + window;
+    ctx.db.rematch_expiry().insert(RematchExpiry {
+        scheduled_id: 0,
+        scheduled_at: timestamp.into(),
+        game_id: game.id.clone(),
+    });
+}
+
+/// Withdraws every rematch `player` was part of, their opponent sees it go away
+pub fn withdraw_rematches(ctx: &ReducerContext, player: Identity) {
+    let rematches: Vec<Rematch> = ctx
+        .db
+        .rematch()
+        .iter()
+        .filter(|r| r.x_player == player || r.o_player == player)
+        .collect();
+    for rematch in rematches {
+        ctx.db.rematch().delete(rematch);
+    }
+}
+
+impl Rematch {
+    fn side_of(&self, player: Identity) -> Option<Player> {
+        if player == self.x_player {
+            Some(Player::X)
+        } else if player == self.o_player {
+            Some(Player::O)
+        } else {
+            None
+        }
+    }
+
+    fn wants(&self, side: Player) -> bool {
+        match side {
+            Player::X => self.x_wants,
+            Player::O => self.o_wants,
+        }
+    }
+
+    fn opponent_wants(&self, side: Player) -> bool {
+        match side {
+            Player::X => self.o_wants,
+            Player::O => self.x_wants,
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use std::time::Duration;
+
+use spacetimedb::{
+    client_visibility_filter, Filter, Identity, ReducerContext, ScheduleAt, Table, TimeDuration,
+    Timestamp,
+};
+
+use crate::chat::chat_message as _;
+use crate::errors::GameError;
+use crate::game_table::Game;
+use crate::live_game_summary::nickname;
+use crate::moderation::{ban_player, check_admin};
+use crate::types::{ReportAction, ReportReason};
+
+// Games are deleted as soon as they end, their snapshot is kept this long to be reported
+const REPORT_WINDOW_SECS: u64 = 600;
+const MAX_REPORT_LENGTH: usize = 280;
+
+/// What a finished game looked like, kept around for the players to report it
+#[spacetimedb::table(name = game_snapshot)]
+pub struct GameSnapshot {
+    #[primary_key]
+    pub game_id: String,
+    pub x_player: Identity,
+    pub o_player: Identity,
+    pub board: String,
+    pub turn: u8,
+    pub moves: Vec<u8>,
+    pub chat_log: String,
+}
+
+#[spacetimedb::table(name = game_snapshot_expiry, scheduled(scheduled_game_snapshot_expiry))]
+pub struct GameSnapshotExpiry {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    game_id: String,
+}
+
+// RLS
+// Only admins see the queue, whoever reported and whoever was reported don't
+#[client_visibility_filter]
+const PLAYER_REPORT_ADMIN_FILTER: Filter = Filter::Sql(
+    "SELECT player_report.* FROM player_report JOIN admin WHERE admin.identity = :sender",
+);
+#[spacetimedb::table(name = player_report, public)]
+pub struct PlayerReport {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    #[index(btree)]
+    pub reporter: Identity,
+    #[index(btree)]
+    pub reported: Identity,
+    pub reason: ReportReason,
+    pub text: String,
+    pub game_id: String,
+    /// Rows of the final board, top first, `.` for an empty cell
+    pub board: String,
+    pub turn: u8,
+    /// Cells in the order they were marked, `0` top left to `8` bottom right
+    pub moves: Vec<u8>,
+    /// The game's chat, one `nickname: text` line per message
+    pub chat_log: String,
+    pub reported_at: Timestamp,
+    pub resolution: Option<ReportAction>,
+    pub resolved_by: Option<Identity>,
+}
+
+/// Reports the opponent of a game that just ended, along with a snapshot of it
+#[spacetimedb::reducer]
+pub fn report_player(
+    ctx: &ReducerContext,
+    game_id: String,
+    reason: ReportReason,
+    text: String,
+) -> Result<(), GameError> {
+    let Some(snapshot) = ctx.db.game_snapshot().game_id().find(game_id.clone()) else {
+        return Err(GameError::not_found("game", game_id));
+    };
+    let reported = if ctx.sender == snapshot.x_player {
+        snapshot.o_player
+    } else if ctx.sender == snapshot.o_player {
+        snapshot.x_player
+    } else {
+        return Err(GameError::NotParticipant(game_id));
+    };
+    let text = text.trim().to_string();
+    if text.chars().count() > MAX_REPORT_LENGTH {
+        return Err(GameError::InvalidArgument(format!(
+            "Reports are at most {MAX_REPORT_LENGTH} characters."
+        )));
+    }
+    if ctx
+        .db
+        .player_report()
+        .reporter()
+        .filter(ctx.sender)
+        .any(|r| r.game_id == game_id)
+    {
+        return Err(GameError::AlreadyDone("Already reported."));
+    }
+
+    ctx.db.player_report().insert(PlayerReport {
+        id: 0,
+        reporter: ctx.sender,
+        reported,
+        reason,
+        text,
+        game_id,
+        board: snapshot.board,
+        turn: snapshot.turn,
+        moves: snapshot.moves,
+        chat_log: snapshot.chat_log,
+        reported_at: ctx.timestamp,
+        resolution: None,
+        resolved_by: None,
+    });
+    Ok(())
+}
+
+/// Closes a report, `ban_minutes` is only used to ban the reported player (0 for good)
+#[spacetimedb::reducer]
+pub fn resolve_report(
+    ctx: &ReducerContext,
+    report_id: u32,
+    action: ReportAction,
+    ban_minutes: u32,
+) -> Result<(), GameError> {
+    check_admin(ctx)?;
+    let Some(mut report) = ctx.db.player_report().id().find(report_id) else {
+        return Err(GameError::not_found("report", report_id));
+    };
+    if report.resolution.is_some() {
+        return Err(GameError::AlreadyDone("Report already resolved."));
+    }
+
+    if action == ReportAction::Banned {
+        let reason = match report.reason {
+            ReportReason::Cheating => "Cheating",
+            ReportReason::Harassment => "Harassment",
+            ReportReason::Stalling => "Stalling",
+            ReportReason::Other => "Reported",
+        };
+        ban_player(
+            ctx,
+            report.reported.to_string(),
+            ban_minutes,
+            reason.to_string(),
+        )?;
+    }
+    report.resolution = Some(action);
+    report.resolved_by = Some(ctx.sender);
+    ctx.db.player_report().id().update(report);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn scheduled_game_snapshot_expiry(
+    ctx: &ReducerContext,
+    arg: GameSnapshotExpiry,
+) -> Result<(), GameError> {
+    if ctx.sender != ctx.identity() {
+        return Err(GameError::SchedulerOnly);
+    }
+    ctx.db.game_snapshot().game_id().delete(arg.game_id);
+    Ok(())
+}
+
+/// Keeps the board and chat of a game that is going away, bot games can't be reported
+pub fn snapshot_game(ctx: &ReducerContext, game: &Game) {
+    if game.bot_difficulty.is_some() {
+        return;
+    }
+    let board = (0..3)
+        .map(|row| {
+            (0..3)
+                .map(|col| {
+                    let cell = 1 << (row * 3 + col);
+                    if game.x_mask & cell != 0 {
+                        'X'
+                    } else if game.o_mask & cell != 0 {
+                        'O'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("/");
+    let moves = game
+        .moves
+        .iter()
+        .map(|cell| cell.trailing_zeros() as u8)
+        .collect();
+
+    let mut messages: Vec<_> = ctx
+        .db
+        .chat_message()
+        .channel()
+        .filter(game.id.as_str())
+        .collect();
+    messages.sort_by_key(|m| m.id);
+    let chat_log = messages
+        .into_iter()
+        .map(|m| format!("{}: {}", nickname(ctx, m.sender), m.text))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    ctx.db.game_snapshot().game_id().delete(game.id.clone());
+    ctx.db.game_snapshot().insert(GameSnapshot {
+        game_id: game.id.clone(),
+        x_player: game.x_player,
+        o_player: game.o_player,
+        board,
+        turn: game.turn,
+        moves,
+        chat_log,
+    });
+
+    let window = TimeDuration::from_duration(Duration::from_secs(REPORT_WINDOW_SECS));
+    let timestamp: Timestamp = ctx.timestamp + window;
+    ctx.db.game_snapshot_expiry().insert(GameSnapshotExpiry {
+        scheduled_id: 0,
+        scheduled_at: timestamp.into(),
+        game_id: game.id.clone(),
+    });
+}
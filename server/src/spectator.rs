@@ -0,0 +1,70 @@
+use spacetimedb::{client_visibility_filter, Filter, Identity, ReducerContext, Table};
+
+use crate::errors::GameError;
+use crate::game_table::game as _;
+use crate::live_game_summary::refresh_spectator_count;
+use crate::presence::is_playing;
+
+// RLS
+// Filters on the same table are combined, the watcher and the game's players see who is watching.
+// The players are copied on the row, joining the game would loop back through its own filter
+#[client_visibility_filter]
+const SPECTATOR_WATCHER_FILTER: Filter =
+    Filter::Sql("SELECT * FROM spectator WHERE identity = :sender");
+#[client_visibility_filter]
+const SPECTATOR_X_FILTER: Filter = Filter::Sql("SELECT * FROM spectator WHERE x_player = :sender");
+#[client_visibility_filter]
+const SPECTATOR_O_FILTER: Filter = Filter::Sql("SELECT * FROM spectator WHERE o_player = :sender");
+/// A client following a game it doesn't play in, one game at a time
+#[spacetimedb::table(name = spectator, public)]
+pub struct Spectator {
+    #[primary_key]
+    pub identity: Identity,
+    #[index(btree)]
+    pub game_id: String,
+    pub x_player: Identity,
+    pub o_player: Identity,
+}
+
+#[spacetimedb::reducer]
+pub fn watch_game(ctx: &ReducerContext, game_id: String) -> Result<(), GameError> {
+    let Some(game) = ctx.db.game().id().find(game_id.clone()) else {
+        return Err(GameError::not_found("game", game_id));
+    };
+    if ctx.sender == game.x_player || ctx.sender == game.o_player {
+        return Err(GameError::NotAllowed("Players can't watch their own game."));
+    }
+    if is_playing(ctx, ctx.sender) {
+        return Err(GameError::Busy);
+    }
+
+    // Watching another game stops watching the previous one
+    leave_spectating(ctx, ctx.sender);
+    ctx.db.spectator().insert(Spectator {
+        identity: ctx.sender,
+        game_id: game_id.clone(),
+        x_player: game.x_player,
+        o_player: game.o_player,
+    });
+    refresh_spectator_count(ctx, &game_id);
+    Ok(())
+}
+
+/// Nothing to do when the game went away first, its spectators were sent home with it
+#[spacetimedb::reducer]
+pub fn stop_watching(ctx: &ReducerContext) -> Result<(), GameError> {
+    leave_spectating(ctx, ctx.sender);
+    Ok(())
+}
+
+pub fn leave_spectating(ctx: &ReducerContext, spectator: Identity) {
+    if let Some(watching) = ctx.db.spectator().identity().find(spectator) {
+        ctx.db.spectator().identity().delete(spectator);
+        refresh_spectator_count(ctx, &watching.game_id);
+    }
+}
+
+/// Sends the spectators home once the game they follow is gone
+pub fn clear_spectators(ctx: &ReducerContext, game_id: &str) {
+    ctx.db.spectator().game_id().delete(game_id);
+}
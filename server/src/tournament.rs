@@ -0,0 +1,626 @@
+use std::time::Duration;
+
+use spacetimedb::rand::seq::SliceRandom;
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, Table, TimeDuration};
+
+use crate::errors::GameError;
+use crate::lobby_room_table::{close_room, generate_game_id, start_game};
+use crate::presence::{is_busy, is_online};
+use crate::types::{GameState, Player, TournamentFormat, TournamentStatus};
+
+const MIN_ENTRANTS: u8 = 2;
+const MAX_ENTRANTS: u8 = 16;
+// How often a running tournament checks whether its round is over
+const ROUND_CHECK_INTERVAL_MS: u64 = 2000;
+// Drawn knockout games replayed before the earlier entrant goes through
+const MAX_REPLAYS: u8 = 2;
+
+#[spacetimedb::table(name = tournament, public)]
+pub struct Tournament {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub owner: Identity,
+    pub format: TournamentFormat,
+    pub status: TournamentStatus,
+    pub max_entrants: u8,
+    /// Round being played, 0 until the tournament starts
+    pub current_round: u8,
+    pub winner: Option<Identity>,
+}
+
+#[spacetimedb::table(name = tournament_entrant, public)]
+pub struct TournamentEntrant {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub tournament_id: u32,
+    pub player: Identity,
+    /// Two points per win, one per draw
+    pub points: u16,
+    pub eliminated: bool,
+}
+
+#[spacetimedb::table(name = tournament_round, public)]
+pub struct TournamentRound {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub tournament_id: u32,
+    pub number: u8,
+    pub finished: bool,
+}
+
+#[spacetimedb::table(name = tournament_pairing, public)]
+#[derive(Clone)]
+pub struct TournamentPairing {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub tournament_id: u32,
+    pub round: u8,
+    #[unique]
+    pub game_id: String,
+    pub x_player: Identity,
+    /// `None` when `x_player` sits the round out with a bye
+    pub o_player: Option<Identity>,
+    pub result: GameState,
+    /// How many times a drawn knockout game was played again
+    pub replays: u8,
+}
+
+#[spacetimedb::table(name = tournament_schedule, scheduled(scheduled_tournament_round))]
+pub struct TournamentSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    #[unique]
+    tournament_id: u32,
+}
+
+#[spacetimedb::reducer]
+pub fn create_tournament(
+    ctx: &ReducerContext,
+    format: TournamentFormat,
+    max_entrants: u8,
+) -> Result<(), GameError> {
+    if !(MIN_ENTRANTS..=MAX_ENTRANTS).contains(&max_entrants) {
+        return Err(GameError::InvalidArgument(format!(
+            "A tournament takes {MIN_ENTRANTS} to {MAX_ENTRANTS} entrants."
+        )));
+    }
+    if ctx
+        .db
+        .tournament()
+        .iter()
+        .any(|t| t.owner == ctx.sender && t.status != TournamentStatus::Finished)
+    {
+        return Err(GameError::AlreadyDone(
+            "You are already running a tournament.",
+        ));
+    }
+
+    let tournament = ctx.db.tournament().insert(Tournament {
+        id: 0,
+        owner: ctx.sender,
+        format,
+        status: TournamentStatus::Registering,
+        max_entrants,
+        current_round: 0,
+        winner: None,
+    });
+    // The owner plays in their own tournament
+    join_tournament(ctx, tournament.id)
+}
+
+#[spacetimedb::reducer]
+pub fn join_tournament(ctx: &ReducerContext, tournament_id: u32) -> Result<(), GameError> {
+    let Some(tournament) = ctx.db.tournament().id().find(tournament_id) else {
+        return Err(GameError::not_found("tournament", tournament_id));
+    };
+    if tournament.status != TournamentStatus::Registering {
+        return Err(GameError::Closed {
+            kind: "tournament",
+            id: tournament_id.to_string(),
+        });
+    }
+
+    let entrants = entrants(ctx, tournament_id);
+    if entrants.iter().any(|e| e.player == ctx.sender) {
+        return Err(GameError::AlreadyDone("Already entered."));
+    }
+    if entrants.len() >= tournament.max_entrants as usize {
+        return Err(GameError::Full {
+            kind: "tournament",
+            id: tournament_id.to_string(),
+        });
+    }
+
+    ctx.db.tournament_entrant().insert(TournamentEntrant {
+        id: 0,
+        tournament_id,
+        player: ctx.sender,
+        points: 0,
+        eliminated: false,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn start_tournament(ctx: &ReducerContext, tournament_id: u32) -> Result<(), GameError> {
+    let Some(mut tournament) = ctx.db.tournament().id().find(tournament_id) else {
+        return Err(GameError::not_found("tournament", tournament_id));
+    };
+    if tournament.owner != ctx.sender {
+        return Err(GameError::NotYours("tournament"));
+    }
+    if tournament.status != TournamentStatus::Registering {
+        return Err(GameError::Closed {
+            kind: "tournament",
+            id: tournament_id.to_string(),
+        });
+    }
+    if entrants(ctx, tournament_id).len() < MIN_ENTRANTS as usize {
+        return Err(GameError::NotAllowed("Not enough entrants."));
+    }
+
+    tournament.status = TournamentStatus::InProgress;
+    let interval = TimeDuration::from_duration(Duration::from_millis(ROUND_CHECK_INTERVAL_MS));
+    ctx.db.tournament_schedule().insert(TournamentSchedule {
+        scheduled_id: 0,
+        scheduled_at: interval.into(),
+        tournament_id,
+    });
+
+    // Pair the first round right away
+    advance_tournament(ctx, tournament);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn scheduled_tournament_round(
+    ctx: &ReducerContext,
+    arg: TournamentSchedule,
+) -> Result<(), GameError> {
+    if ctx.sender != ctx.identity() {
+        return Err(GameError::SchedulerOnly);
+    }
+    match ctx.db.tournament().id().find(arg.tournament_id) {
+        Some(tournament) if tournament.status == TournamentStatus::InProgress => {
+            advance_tournament(ctx, tournament);
+        }
+        _ => {
+            ctx.db.tournament_schedule().delete(arg);
+        }
+    }
+    Ok(())
+}
+
+/// Stores the result of a tournament game, the next check on its tournament picks it up.
+pub fn record_tournament_result(ctx: &ReducerContext, game_id: &str, result: GameState) {
+    let Some(mut pairing) = ctx
+        .db
+        .tournament_pairing()
+        .game_id()
+        .find(game_id.to_string())
+    else {
+        return;
+    };
+    if pairing.result != GameState::InProgress {
+        return;
+    }
+    pairing.result = result;
+    ctx.db.tournament_pairing().id().update(pairing);
+}
+
+/// Takes the player out of the tournaments that haven't started yet
+pub fn leave_tournaments(ctx: &ReducerContext, player: Identity) {
+    let registering: Vec<TournamentEntrant> = ctx
+        .db
+        .tournament_entrant()
+        .iter()
+        .filter(|e| e.player == player)
+        .filter(|e| {
+            ctx.db
+                .tournament()
+                .id()
+                .find(e.tournament_id)
+                .is_some_and(|t| t.status == TournamentStatus::Registering)
+        })
+        .collect();
+    for entrant in registering {
+        ctx.db.tournament_entrant().id().delete(entrant.id);
+    }
+}
+
+/// Once every game of the current round is over, settles it and pairs the next round.
+fn advance_tournament(ctx: &ReducerContext, mut tournament: Tournament) {
+    let mut pairings: Vec<TournamentPairing> = ctx
+        .db
+        .tournament_pairing()
+        .tournament_id()
+        .filter(tournament.id)
+        .filter(|p| p.round == tournament.current_round)
+        .collect();
+    if pairings.iter().any(|p| p.result == GameState::InProgress) {
+        return;
+    }
+
+    if tournament.current_round > 0 {
+        // Knockout games need a winner, drawn ones are replayed with the sides swapped
+        // until they run out of replays, then the earlier entrant goes through
+        if tournament.format == TournamentFormat::SingleElimination {
+            let mut replayed = false;
+            for pairing in pairings.iter_mut().filter(|p| p.result == GameState::Draw) {
+                if pairing.replays < MAX_REPLAYS {
+                    replay_pairing(ctx, pairing.clone());
+                    replayed = true;
+                } else {
+                    pairing.result = seeded_winner(ctx, tournament.id, pairing);
+                    ctx.db.tournament_pairing().id().update(pairing.clone());
+                }
+            }
+            if replayed {
+                return;
+            }
+        }
+        settle_round(ctx, &tournament, &pairings);
+    }
+
+    match next_round_pairs(ctx, &tournament) {
+        Some(pairs) => {
+            tournament.current_round += 1;
+            ctx.db.tournament_round().insert(TournamentRound {
+                id: 0,
+                tournament_id: tournament.id,
+                number: tournament.current_round,
+                finished: false,
+            });
+            for (x_player, o_player) in pairs {
+                spawn_pairing(ctx, &tournament, x_player, o_player);
+            }
+        }
+        None => {
+            tournament.status = TournamentStatus::Finished;
+            tournament.winner = standings_leader(ctx, tournament.id);
+            ctx.db
+                .tournament_schedule()
+                .tournament_id()
+                .delete(tournament.id);
+            log::info!("Tournament '{}' finished", tournament.id);
+        }
+    }
+    ctx.db.tournament().id().update(tournament);
+}
+
+fn settle_round(ctx: &ReducerContext, tournament: &Tournament, pairings: &[TournamentPairing]) {
+    if let Some(mut round) = ctx
+        .db
+        .tournament_round()
+        .tournament_id()
+        .filter(tournament.id)
+        .find(|r| r.number == tournament.current_round)
+    {
+        round.finished = true;
+        ctx.db.tournament_round().id().update(round);
+    }
+
+    let knockout = tournament.format == TournamentFormat::SingleElimination;
+    for pairing in pairings {
+        let (winner, loser) = match pairing.result {
+            GameState::Winner(Player::X) => (Some(pairing.x_player), pairing.o_player),
+            GameState::Winner(Player::O) => (pairing.o_player, Some(pairing.x_player)),
+            _ => {
+                // A draw, only round robins get this far with one
+                award(ctx, tournament.id, pairing.x_player, 1, false);
+                if let Some(o_player) = pairing.o_player {
+                    award(ctx, tournament.id, o_player, 1, false);
+                }
+                continue;
+            }
+        };
+        if let Some(winner) = winner {
+            award(ctx, tournament.id, winner, 2, false);
+        }
+        if let Some(loser) = loser {
+            award(ctx, tournament.id, loser, 0, knockout);
+        }
+    }
+}
+
+fn award(
+    ctx: &ReducerContext,
+    tournament_id: u32,
+    player: Identity,
+    points: u16,
+    eliminated: bool,
+) {
+    if let Some(mut entrant) = entrants(ctx, tournament_id)
+        .into_iter()
+        .find(|e| e.player == player)
+    {
+        entrant.points += points;
+        entrant.eliminated |= eliminated;
+        ctx.db.tournament_entrant().id().update(entrant);
+    }
+}
+
+/// Pairs for the next round, `None` when the tournament is over.
+fn next_round_pairs(
+    ctx: &ReducerContext,
+    tournament: &Tournament,
+) -> Option<Vec<(Identity, Option<Identity>)>> {
+    let entrants = entrants(ctx, tournament.id);
+    match tournament.format {
+        TournamentFormat::SingleElimination => {
+            let mut remaining: Vec<Identity> = entrants
+                .into_iter()
+                .filter(|e| !e.eliminated)
+                .map(|e| e.player)
+                .collect();
+            if remaining.len() < 2 {
+                return None;
+            }
+            let mut rng = ctx.rng();
+            remaining.shuffle(&mut rng);
+            // With an odd count the last one left gets a bye
+            Some(
+                remaining
+                    .chunks(2)
+                    .map(|pair| (pair[0], pair.get(1).copied()))
+                    .collect(),
+            )
+        }
+        TournamentFormat::RoundRobin => {
+            let players: Vec<Identity> = entrants.iter().map(|e| e.player).collect();
+            round_robin_pairs(&players, tournament.current_round)
+        }
+    }
+}
+
+/// Pairs of round robin round `round`, counting from 0, `None` once every round was played
+fn round_robin_pairs(players: &[Identity], round: u8) -> Option<Vec<(Identity, Option<Identity>)>> {
+    // Circle method: the first seat stays put while the others rotate each round
+    let mut seats: Vec<Option<Identity>> = players.iter().copied().map(Some).collect();
+    if seats.len() % 2 != 0 {
+        seats.push(None);
+    }
+    let rounds = seats.len().saturating_sub(1);
+    if round as usize >= rounds {
+        return None;
+    }
+    seats[1..].rotate_right(round as usize);
+
+    let n = seats.len();
+    Some(
+        (0..n / 2)
+            .filter_map(|i| match (seats[i], seats[n - 1 - i]) {
+                (Some(x_player), o_player) => Some((x_player, o_player)),
+                (None, Some(x_player)) => Some((x_player, None)),
+                (None, None) => None,
+            })
+            .collect(),
+    )
+}
+
+fn spawn_pairing(
+    ctx: &ReducerContext,
+    tournament: &Tournament,
+    x_player: Identity,
+    o_player: Option<Identity>,
+) {
+    let game_id = generate_game_id(ctx);
+    let result = match o_player {
+        Some(o_player) => unavailable_result(ctx, x_player, o_player).unwrap_or_else(|| {
+            start_tournament_game(ctx, x_player, o_player, game_id.clone());
+            GameState::InProgress
+        }),
+        // A bye counts as a win
+        None => GameState::Winner(Player::X),
+    };
+    ctx.db.tournament_pairing().insert(TournamentPairing {
+        id: 0,
+        tournament_id: tournament.id,
+        round: tournament.current_round,
+        game_id,
+        x_player,
+        o_player,
+        result,
+        replays: 0,
+    });
+}
+
+fn replay_pairing(ctx: &ReducerContext, mut pairing: TournamentPairing) {
+    let Some(o_player) = pairing.o_player else {
+        return;
+    };
+    let x_player = pairing.x_player;
+    pairing.x_player = o_player;
+    pairing.o_player = Some(x_player);
+    pairing.game_id = generate_game_id(ctx);
+    pairing.replays += 1;
+    pairing.result = unavailable_result(ctx, o_player, x_player).unwrap_or_else(|| {
+        start_tournament_game(ctx, o_player, x_player, pairing.game_id.clone());
+        GameState::InProgress
+    });
+    ctx.db.tournament_pairing().id().update(pairing);
+}
+
+/// The result of a pairing someone is offline or already playing for, they forfeit it without a
+/// game. With neither of them free it's a draw.
+fn unavailable_result(
+    ctx: &ReducerContext,
+    x_player: Identity,
+    o_player: Identity,
+) -> Option<GameState> {
+    let available = |player| is_online(ctx, player) && !is_busy(ctx, player);
+    match (available(x_player), available(o_player)) {
+        (true, true) => None,
+        (true, false) => Some(GameState::Winner(Player::X)),
+        (false, true) => Some(GameState::Winner(Player::O)),
+        (false, false) => Some(GameState::Draw),
+    }
+}
+
+/// Breaks a tie the same way the standings do, in favour of whoever entered first
+fn seeded_winner(
+    ctx: &ReducerContext,
+    tournament_id: u32,
+    pairing: &TournamentPairing,
+) -> GameState {
+    let seeds: Vec<Identity> = entrants(ctx, tournament_id)
+        .iter()
+        .map(|e| e.player)
+        .collect();
+    winner_by_seed(&seeds, pairing)
+}
+
+/// The side of `pairing` that comes first in `seeds`, X when neither does
+fn winner_by_seed(seeds: &[Identity], pairing: &TournamentPairing) -> GameState {
+    let seed = |player: Identity| {
+        seeds
+            .iter()
+            .position(|&seeded| seeded == player)
+            .unwrap_or(usize::MAX)
+    };
+    match pairing.o_player {
+        Some(o_player) if seed(o_player) < seed(pairing.x_player) => GameState::Winner(Player::O),
+        _ => GameState::Winner(Player::X),
+    }
+}
+
+fn start_tournament_game(ctx: &ReducerContext, x_player: Identity, o_player: Identity, id: String) {
+    // Nobody waits in the lobby while their tournament game is on
+    close_room(ctx, x_player);
+    close_room(ctx, o_player);
+
+    start_game(ctx, x_player, o_player, id, true);
+}
+
+fn standings_leader(ctx: &ReducerContext, tournament_id: u32) -> Option<Identity> {
+    let entrants = entrants(ctx, tournament_id);
+    // Ties go to whoever entered first
+    entrants
+        .iter()
+        .filter(|e| !e.eliminated)
+        .max_by_key(|e| (e.points, std::cmp::Reverse(e.id)))
+        .map(|e| e.player)
+}
+
+fn entrants(ctx: &ReducerContext, tournament_id: u32) -> Vec<TournamentEntrant> {
+    let mut entrants: Vec<TournamentEntrant> = ctx
+        .db
+        .tournament_entrant()
+        .tournament_id()
+        .filter(tournament_id)
+        .collect();
+    entrants.sort_by_key(|e| e.id);
+    entrants
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn players(count: u8) -> Vec<Identity> {
+        (1..=count)
+            .map(|n| Identity::from_byte_array([n; 32]))
+            .collect()
+    }
+
+    fn pairing(x_player: Identity, o_player: Identity) -> TournamentPairing {
+        TournamentPairing {
+            id: 0,
+            tournament_id: 0,
+            round: 0,
+            game_id: String::new(),
+            x_player,
+            o_player: Some(o_player),
+            result: GameState::Draw,
+            replays: 0,
+        }
+    }
+
+    /// Every pair of players, as a set so the side doesn't matter
+    fn games_played(players: &[Identity]) -> Vec<HashSet<Identity>> {
+        (0..)
+            .map_while(|round| round_robin_pairs(players, round))
+            .flatten()
+            .filter_map(|(x_player, o_player)| Some(HashSet::from([x_player, o_player?])))
+            .collect()
+    }
+
+    #[test]
+    fn round_robin_plays_everyone_once() {
+        for count in 2..=7 {
+            let players = players(count);
+            let games = games_played(&players);
+            let n = count as usize;
+            assert_eq!(games.len(), n * (n - 1) / 2);
+            for (i, a) in players.iter().enumerate() {
+                for b in &players[i + 1..] {
+                    let pair = HashSet::from([*a, *b]);
+                    assert_eq!(games.iter().filter(|g| **g == pair).count(), 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_robin_rounds_seat_everyone_once() {
+        let players = players(5);
+        let mut rounds = 0;
+        while let Some(pairs) = round_robin_pairs(&players, rounds) {
+            let mut seated: Vec<Identity> = pairs
+                .iter()
+                .flat_map(|&(x_player, o_player)| std::iter::once(x_player).chain(o_player))
+                .collect();
+            seated.sort();
+            let mut expected = players.clone();
+            expected.sort();
+            assert_eq!(seated, expected);
+            // With an odd count somebody sits each round out
+            assert_eq!(pairs.iter().filter(|(_, o)| o.is_none()).count(), 1);
+            rounds += 1;
+        }
+        assert_eq!(rounds, 5);
+    }
+
+    #[test]
+    fn round_robin_of_one_has_no_rounds() {
+        assert!(round_robin_pairs(&players(1), 0).is_none());
+        assert!(round_robin_pairs(&[], 0).is_none());
+    }
+
+    #[test]
+    fn seeded_winner_is_who_entered_first() {
+        let seeds = players(3);
+        assert!(matches!(
+            winner_by_seed(&seeds, &pairing(seeds[2], seeds[0])),
+            GameState::Winner(Player::O)
+        ));
+        assert!(matches!(
+            winner_by_seed(&seeds, &pairing(seeds[1], seeds[2])),
+            GameState::Winner(Player::X)
+        ));
+    }
+
+    #[test]
+    fn seeded_winner_favours_x_when_unseeded() {
+        let seeds = players(2);
+        let strangers = [
+            Identity::from_byte_array([8; 32]),
+            Identity::from_byte_array([9; 32]),
+        ];
+        assert!(matches!(
+            winner_by_seed(&seeds, &pairing(strangers[0], strangers[1])),
+            GameState::Winner(Player::X)
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use spacetimedb::SpacetimeType;
+use tictactoe_rules::Difficulty;
 
 #[derive(SpacetimeType, Default, Clone, Copy, PartialEq)]
 pub enum Player {
@@ -7,9 +8,114 @@ pub enum Player {
     O,
 }
 
+/// Side requested by a room owner, resolved once an opponent joins.
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum SidePreference {
+    X,
+    O,
+    Random,
+}
+
+/// How hard the server-owned bot plays, from random moves up to perfect play.
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum BotDifficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl From<BotDifficulty> for Difficulty {
+    fn from(difficulty: BotDifficulty) -> Self {
+        match difficulty {
+            BotDifficulty::Easy => Difficulty::Easy,
+            BotDifficulty::Medium => Difficulty::Medium,
+            BotDifficulty::Hard => Difficulty::Hard,
+        }
+    }
+}
+
 #[derive(SpacetimeType, Clone, PartialEq)]
 pub enum GameState {
     InProgress,
     Draw,
     Winner(Player),
 }
+
+impl GameState {
+    pub fn winner(&self) -> Option<Player> {
+        match self {
+            GameState::Winner(player) => Some(*player),
+            _ => None,
+        }
+    }
+}
+
+/// Why a game was deleted.
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum GameEndReason {
+    /// Played to the end, then left
+    Finished,
+    /// A player left or disconnected mid-game, the opponent won
+    Forfeited,
+    /// A player was banned mid-game, the opponent won
+    Banned,
+    /// Ended by an admin without a result
+    Terminated,
+}
+
+/// How a tournament pairs its entrants from one round to the next.
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum TournamentFormat {
+    SingleElimination,
+    RoundRobin,
+}
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum TournamentStatus {
+    Registering,
+    InProgress,
+    Finished,
+}
+
+/// Quick reactions a player can throw at the opponent during a game.
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum Emote {
+    ThumbsUp,
+    Wow,
+    GoodGame,
+    Hurry,
+}
+
+/// What a player is up to, as shown to their friends.
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum PresenceStatus {
+    Offline,
+    Online,
+    InLobby,
+    InGame,
+}
+
+/// Why a player got reported.
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum ReportReason {
+    Cheating,
+    Harassment,
+    Stalling,
+    Other,
+}
+
+/// What an admin did about a report.
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum ReportAction {
+    Dismissed,
+    Banned,
+}
+
+/// Groups of reducers sharing a rate limit.
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum RateLimitCategory {
+    CreateRoom,
+    Join,
+    Chat,
+    Move,
+}